@@ -5,7 +5,7 @@ use puzzle_cube::core::prelude::*;
 
 criterion_group!(
     name = benches;
-    config = Criterion::default().sample_size(100).measurement_time(instant::Duration::new(5, 0));
+    config = Criterion::default().sample_size(100).measurement_time(std::time::Duration::new(5, 0));
 
     targets=
 
@@ -39,23 +39,23 @@ fn bench_create_ud_edges_conjugation(c: &mut Criterion) {
 }
 
 fn bench_create_corner_slice_depth(c: &mut Criterion) {
-    let moves_source = MovesSource::create();
+    let moves_source = MovesSource::create(&mut IgnoreProgress).unwrap();
     c.bench_function("create corner slice depth", |bench|bench.iter(||create_corner_slice_depth(&moves_source)));
 }
 
 fn bench_create_phase_2_pruning(c: &mut Criterion) {
-    let moves_source = MovesSource::create();
-    let corner_source = CornerSymmetriesSource::create();
+    let moves_source = MovesSource::create(&mut IgnoreProgress).unwrap();
+    let corner_source = CornerSymmetriesSource::create(&mut IgnoreProgress).unwrap();
     c.bench_function("create phase 2 pruning", |bench|bench.iter(||create_phase_2_pruning(&moves_source, &corner_source)));    
 }
 
 fn bench_create_phase_1_pruning(c: &mut Criterion) {
-    let moves_source = MovesSource::create();
-    let flip_slice_source = FlipSliceSource::create();
+    let moves_source = MovesSource::create(&mut IgnoreProgress).unwrap();
+    let flip_slice_source = FlipSliceSource::create(&mut IgnoreProgress).unwrap();
 
     let mut group = c.benchmark_group("phase one pruning");
     group.sample_size(10);
-    group.measurement_time(instant::Duration::new(100, 0));
+    group.measurement_time(std::time::Duration::new(100, 0));
     group.bench_function("create phase one pruning slow", |bench|bench.iter(||create_phase_1_pruning(false,&moves_source, &flip_slice_source)));
     group.bench_function("create phase one pruning quick", |bench|bench.iter(||create_phase_1_pruning(true,&moves_source, &flip_slice_source)));
     group.finish()
@@ -71,11 +71,11 @@ fn bench_solver(c: &mut Criterion) {
 }
 
 fn create_move_table() -> MovesSource {
-    MovesSource::create()
+    MovesSource::create(&mut IgnoreProgress).unwrap()
 }
 
 fn create_corner_slice_depth(moves_source: &MovesSource) -> Vec<u8> {
-    DataSource::create_corner_slice_depth(moves_source)
+    DataSource::create_corner_slice_depth(moves_source, &mut IgnoreProgress).unwrap()
 }
 
 fn create_ud_edges_conjugation() -> Vec<u16> {
@@ -83,18 +83,18 @@ fn create_ud_edges_conjugation() -> Vec<u16> {
 }
 
 fn create_corner_symmetries() -> CornerSymmetriesSource {
-    CornerSymmetriesSource::create()
+    CornerSymmetriesSource::create(&mut IgnoreProgress).unwrap()
 }
 
 fn create_flip_slice() -> FlipSliceSource {
-    FlipSliceSource::create()
+    FlipSliceSource::create(&mut IgnoreProgress).unwrap()
 }
 
 fn create_phase_2_pruning(
     move_source: &MovesSource,
     corner_source: &CornerSymmetriesSource,
 ) -> Vec<u32> {
-    DataSource::create_phase_2_pruning(move_source, corner_source)
+    DataSource::create_phase_2_pruning(move_source, corner_source, &mut IgnoreProgress).unwrap()
 }
 
 fn create_phase_1_pruning(
@@ -102,7 +102,7 @@ fn create_phase_1_pruning(
     move_source: &MovesSource,
     flip_slice_source: &FlipSliceSource,
 ) -> Vec<u32> {
-    DataSource::create_phase_1_pruning(quick, move_source, flip_slice_source)
+    DataSource::create_phase_1_pruning(quick, move_source, flip_slice_source, &mut IgnoreProgress).unwrap()
 }
 
 fn solve(data_source: Rc<DataSource>, number: u64) {
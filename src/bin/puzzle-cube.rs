@@ -0,0 +1,218 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter};
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::Duration;
+
+use puzzle_cube::core::prelude::*;
+
+const USAGE: &str = "Usage:
+    puzzle-cube solve [OPTIONS] [CUBE]...
+    puzzle-cube generate-tables [--quick] <PATH>
+
+Each cube is a 54 character facelet string (URFDLB order, using the face letters),
+a scramble in move notation or the JSON of a CubieCube.
+If no cubes are given they are read from stdin, one per line.
+
+Options:
+    --stopping-length <N>   Stop once a solution of fewer than N moves is found [default: 20]
+    --max-iterations <N>    Give up after this many search iterations [default: 10000]
+    --time-limit <MS>       Stop searching after this many milliseconds and print the best solution so far
+    --report                Print statistics about each search to stderr
+    --tables <PATH>         Load tables written by generate-tables instead of creating them
+    --quick                 Create quick tables (faster to create, slower to solve)";
+
+struct SolveArgs {
+    stopping_length: u8,
+    max_iterations: usize,
+    time_limit: Option<Duration>,
+    report: bool,
+    tables: Option<String>,
+    quick: bool,
+    cubes: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    let result = match args.split_first() {
+        Some((command, rest)) if command == "solve" => {
+            parse_solve_args(rest).and_then(|args| solve(&args))
+        }
+        Some((command, rest)) if command == "generate-tables" => generate_tables(rest),
+        Some((command, _)) if command == "--help" || command == "-h" => {
+            println!("{}", USAGE);
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn parse_solve_args(args: &[String]) -> Result<SolveArgs, String> {
+    let defaults = SolveSettings::default();
+    let mut solve_args = SolveArgs {
+        stopping_length: defaults.stopping_length,
+        max_iterations: defaults.max_iterations,
+        time_limit: defaults.time_budget,
+        report: false,
+        tables: None,
+        quick: false,
+        cubes: Vec::new(),
+    };
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = |name: &str| {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| format!("{} needs a value", name))
+        };
+
+        match arg.as_str() {
+            "--stopping-length" => {
+                solve_args.stopping_length = value(arg)?
+                    .parse()
+                    .map_err(|_| "--stopping-length must be a number from 0 to 255".to_string())?
+            }
+            "--max-iterations" => {
+                solve_args.max_iterations = value(arg)?
+                    .parse()
+                    .map_err(|_| "--max-iterations must be a number".to_string())?
+            }
+            "--time-limit" => {
+                let millis = value(arg)?
+                    .parse()
+                    .map_err(|_| "--time-limit must be a number of milliseconds".to_string())?;
+                solve_args.time_limit = Some(Duration::from_millis(millis))
+            }
+            "--report" => solve_args.report = true,
+            "--tables" => solve_args.tables = Some(value(arg)?),
+            "--quick" => solve_args.quick = true,
+            _ if arg.starts_with("--") => {
+                return Err(format!("Unknown option {}\n\n{}", arg, USAGE))
+            }
+            _ => solve_args.cubes.push(arg.clone()),
+        }
+    }
+
+    Ok(solve_args)
+}
+
+fn solve(args: &SolveArgs) -> Result<(), String> {
+    let data_source = Arc::new(match &args.tables {
+        Some(path) => {
+            let file = File::open(path).map_err(|e| format!("Could not open {}: {}", path, e))?;
+            DataSource::read_from(BufReader::new(file)).map_err(|e| e.to_string())?
+        }
+        None => DataSource::create(args.quick),
+    });
+
+    let inputs: Vec<String> = if args.cubes.is_empty() {
+        std::io::stdin()
+            .lock()
+            .lines()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter(|line| !line.trim().is_empty())
+            .collect()
+    } else {
+        args.cubes.clone()
+    };
+
+    let parsed = inputs
+        .iter()
+        .map(|input| parse_cube(input))
+        .collect::<Vec<_>>();
+    let cubes: Vec<CubieCube> = parsed.iter().filter_map(|p| p.clone().ok()).collect();
+
+    let settings = SolveSettings {
+        stopping_length: args.stopping_length,
+        max_iterations: args.max_iterations,
+        time_budget: args.time_limit,
+        ..Default::default()
+    };
+    //The cubes are solved on all the available cores
+    let mut outcomes = ParallelSolver::solve_batch(&cubes, data_source, settings).into_iter();
+
+    let mut all_solved = true;
+
+    for (input, cube) in inputs.iter().zip(parsed) {
+        let solution = cube.and_then(|_| {
+            let outcome = outcomes.next().expect("Every cube should have an outcome");
+            if args.report {
+                eprintln!("{}\nStopped: {:?}", outcome.report, outcome.stop_reason);
+            }
+            outcome
+                .solution
+                .ok_or_else(|| "No solution found".to_string())
+        });
+
+        match solution {
+            Ok(moves) => println!("{}", Move::format_sequence(&moves)),
+            Err(e) => {
+                eprintln!("{}: {}", input.trim(), e);
+                all_solved = false;
+            }
+        }
+    }
+
+    if all_solved {
+        Ok(())
+    } else {
+        Err("Not every cube could be solved".to_string())
+    }
+}
+
+fn parse_cube(input: &str) -> Result<CubieCube, String> {
+    let input = input.trim();
+
+    if input.starts_with('{') {
+        let cube: CubieCube = serde_json::from_str(input).map_err(|e| e.to_string())?;
+        cube.verify().map_err(|e| e.to_string())?;
+        Ok(cube)
+    } else if input.len() == 54 && !input.contains(char::is_whitespace) {
+        let facelets: FaceletCube = input
+            .parse()
+            .map_err(|e: FaceletParseError| e.to_string())?;
+        CubieCube::try_from(facelets).map_err(|e| e.to_string())
+    } else {
+        let scramble: Algorithm = input.parse().map_err(|e: MoveParseError| e.to_string())?;
+        Ok(scramble.apply(&CubieCube::default()))
+    }
+}
+
+fn generate_tables(args: &[String]) -> Result<(), String> {
+    let quick = args.iter().any(|a| a == "--quick");
+    let path = args
+        .iter()
+        .find(|a| !a.starts_with("--"))
+        .ok_or_else(|| USAGE.to_string())?;
+
+    let mut last_line = String::new();
+    let data_source =
+        DataSource::create_with_observer(quick, &mut |progress: GenerationProgress| {
+            let percent = (progress.overall_fraction() * 100.0) as u8;
+            let line = format!("Generating {} table... {}%", progress.table, percent);
+            if line != last_line {
+                eprint!("\r{:<48}", line);
+                last_line = line;
+            }
+            GenerationControl::Continue
+        })
+        .map_err(|e| e.to_string())?;
+    eprintln!();
+
+    let file = File::create(path).map_err(|e| format!("Could not create {}: {}", path, e))?;
+    data_source
+        .write_to(&mut BufWriter::new(file))
+        .map_err(|e| e.to_string())
+}
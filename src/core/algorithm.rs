@@ -0,0 +1,275 @@
+use crate::core::prelude::*;
+
+use itertools::Itertools;
+use strum::EnumCount;
+use strum_macros::*;
+
+/// A sequence of moves
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone, Default, Hash)]
+pub struct Algorithm {
+    pub moves: Vec<Move>,
+}
+
+/// A plane through the centre of the cube which an algorithm can be mirrored across
+#[derive(
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    Copy,
+    Clone,
+    EnumIter,
+)]
+pub enum MirrorPlane {
+    /// The plane between the L and R faces
+    LeftRight,
+    /// The plane between the U and D faces
+    UpDown,
+    /// The plane between the F and B faces
+    FrontBack,
+}
+
+impl MirrorPlane {
+    /// The face that a face is sent to by this reflection
+    pub const fn reflect_face(self, face: FaceColor) -> FaceColor {
+        match (self, face) {
+            (MirrorPlane::LeftRight, FaceColor::Left) => FaceColor::Right,
+            (MirrorPlane::LeftRight, FaceColor::Right) => FaceColor::Left,
+            (MirrorPlane::UpDown, FaceColor::Up) => FaceColor::Down,
+            (MirrorPlane::UpDown, FaceColor::Down) => FaceColor::Up,
+            (MirrorPlane::FrontBack, FaceColor::Front) => FaceColor::Back,
+            (MirrorPlane::FrontBack, FaceColor::Back) => FaceColor::Front,
+            (_, f) => f,
+        }
+    }
+
+    /// The index in `SYMMETRY_CUBES` of the reflection across each plane
+    const SYMMETRY_INDICES: [usize; 3] = [1, 9, 5];
+
+    /// Gets the symmetry cube which reflects across this plane
+    pub fn get_symmetry_cube(self) -> &'static CubieCube {
+        &SYMMETRY_CUBES[Self::SYMMETRY_INDICES[self as usize]]
+    }
+
+    /// For each plane, the move that each move is reflected to.
+    /// A reflected move turns the reflected face in the opposite direction.
+    const MIRRORED_MOVES: [[Move; Move::COUNT]; 3] = Self::create_mirrored_moves();
+
+    const fn create_mirrored_moves() -> [[Move; Move::COUNT]; 3] {
+        let planes = [
+            MirrorPlane::LeftRight,
+            MirrorPlane::UpDown,
+            MirrorPlane::FrontBack,
+        ];
+        let mut table = [Move::ALLMOVES; 3];
+
+        let mut p = 0;
+        while p < planes.len() {
+            let mut i = 0;
+            while i < Move::COUNT {
+                let m = Move::ALLMOVES[i];
+                table[p][m as usize] =
+                    Move::from((planes[p].reflect_face(m.get_color()), m.into_move_number()))
+                        .inverse();
+                i += 1;
+            }
+            p += 1;
+        }
+
+        table
+    }
+}
+
+impl Move {
+    /// The face opposite to this move's face e.g. D for U
+    pub const fn get_opposite_color(self) -> FaceColor {
+        FaceColor::from_repr((self.get_color() as u8 + 3) % 6).unwrap()
+    }
+
+    /// The number of clockwise quarter turns this move makes (1, 2 or 3)
+    const fn quarter_turns(self) -> u8 {
+        self.into_move_number() as u8
+    }
+
+    /// Combine two moves of the same face. Returns None if they cancel out.
+    const fn merge(self, other: Move) -> Option<Move> {
+        match (self.quarter_turns() + other.quarter_turns()) % 4 {
+            0 => None,
+            n => Some(Move::from((
+                self.get_color(),
+                MoveNumber::from_repr(n).unwrap(),
+            ))),
+        }
+    }
+
+    /// Reflect this move across a plane.
+    /// Reflection reverses the direction of every turn.
+    pub fn mirror(self, plane: MirrorPlane) -> Move {
+        MirrorPlane::MIRRORED_MOVES[plane as usize][self as usize]
+    }
+}
+
+impl Algorithm {
+    pub fn new(moves: Vec<Move>) -> Self {
+        Self { moves }
+    }
+
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// Merges adjacent moves of the same face, removing any which cancel out.
+    /// Moves of opposite faces commute so they are merged through each other and put in a canonical order (U before D, R before L, F before B).
+    pub fn simplify(&self) -> Self {
+        let mut result: Vec<Move> = Vec::with_capacity(self.moves.len());
+
+        for &m in self.moves.iter() {
+            let len = result.len();
+
+            let same_face_index = if len >= 1 && result[len - 1].get_color() == m.get_color() {
+                Some(len - 1)
+            } else if len >= 2
+                && result[len - 1].get_color() == m.get_opposite_color()
+                && result[len - 2].get_color() == m.get_color()
+            {
+                Some(len - 2)
+            } else {
+                None
+            };
+
+            match same_face_index {
+                Some(i) => match result[i].merge(m) {
+                    Some(merged) => result[i] = merged,
+                    None => {
+                        result.remove(i);
+                    }
+                },
+                None => {
+                    result.push(m);
+                    let len = result.len();
+                    if len >= 2
+                        && result[len - 2].get_color() == m.get_opposite_color()
+                        && result[len - 2].get_color() > m.get_color()
+                    {
+                        result.swap(len - 2, len - 1);
+                    }
+                }
+            }
+        }
+
+        Self { moves: result }
+    }
+
+    /// The algorithm which undoes this algorithm
+    pub fn invert(&self) -> Self {
+        Self {
+            moves: self.moves.iter().rev().map(|m| m.inverse()).collect_vec(),
+        }
+    }
+
+    /// Reflect this algorithm across a plane
+    pub fn mirror(&self, plane: MirrorPlane) -> Self {
+        Self {
+            moves: self.moves.iter().map(|m| m.mirror(plane)).collect_vec(),
+        }
+    }
+
+    /// Apply this algorithm to a cube
+    pub fn apply(&self, cube: &CubieCube) -> CubieCube {
+        self.moves.iter().fold(cube.clone(), |c, m| m.apply(&c))
+    }
+}
+
+impl From<Vec<Move>> for Algorithm {
+    fn from(moves: Vec<Move>) -> Self {
+        Self { moves }
+    }
+}
+
+impl From<Algorithm> for Vec<Move> {
+    fn from(algorithm: Algorithm) -> Self {
+        algorithm.moves
+    }
+}
+
+impl std::fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Move::format_sequence(&self.moves))
+    }
+}
+
+impl std::str::FromStr for Algorithm {
+    type Err = MoveParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Move::parse_sequence(s).map(|moves| moves.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ntest::test_case;
+    use strum::IntoEnumIterator;
+
+    #[test_case("R R2", "R'")]
+    #[test_case("R U U' R'", "")]
+    #[test_case("D U", "U D")]
+    #[test_case("U D U", "U2 D")]
+    #[test_case("L R L'", "R")]
+    #[test_case("F B2 F' B2", "")]
+    #[test_case("R U R' U'", "R U R' U'")]
+    fn test_simplify(text: &str, expected: &str) {
+        let algorithm: Algorithm = text.parse().unwrap();
+        let simplified = algorithm.simplify();
+        assert_eq!(simplified.to_string(), expected);
+
+        let cube = CubieCube::random_cube(1);
+        assert_eq!(algorithm.apply(&cube), simplified.apply(&cube));
+    }
+
+    #[test]
+    fn test_mirror() {
+        let algorithm: Algorithm = "R U R' U' F2".parse().unwrap();
+
+        assert_eq!(
+            algorithm.mirror(MirrorPlane::LeftRight).to_string(),
+            "L' U' L U F2"
+        );
+        assert_eq!(
+            algorithm.mirror(MirrorPlane::UpDown).to_string(),
+            "R' D' R D F2"
+        );
+        assert_eq!(
+            algorithm.mirror(MirrorPlane::FrontBack).to_string(),
+            "R' U' R U B2"
+        );
+    }
+
+    #[test]
+    fn test_mirror_matches_symmetry() {
+        for plane in MirrorPlane::iter() {
+            let symmetry = plane.get_symmetry_cube();
+            for m in Move::ALLMOVES {
+                let cube = symmetry.multiply(m.get_cube()).multiply(&symmetry.invert());
+                assert_eq!(*m.mirror(plane).get_cube(), cube, "{} across {}", m, plane);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invert() {
+        let algorithm: Algorithm = "R U F2 D' B".parse().unwrap();
+        let cube = algorithm.apply(&CubieCube::default());
+        assert_eq!(algorithm.invert().apply(&cube), CubieCube::default());
+    }
+}
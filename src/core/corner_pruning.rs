@@ -0,0 +1,83 @@
+use crate::core::prelude::*;
+
+impl DataSource {
+    /// Creates a table of the exact number of moves needed to solve the corners (permutation and twist).
+    /// Indexed by corner class index * NTWIST + twist conjugated by the corner symmetry.
+    pub fn create_corner_twist_depth(
+        moves_source: &MovesSource,
+        corners_source: &CornerSymmetriesSource,
+    ) -> Vec<u8> {
+        let c_sym = DataSource::make_corners_sym(corners_source);
+        let mut table = vec![u8::MAX; NCORNERSCLASS * NTWIST];
+
+        table[0] = 0;
+        let mut depth = 0u8;
+
+        let mut next: Vec<(u16, u16)> = vec![(0, 0)]; //next is tuples of (corner_class_index, twist_conj)
+        let mut current: Vec<(u16, u16)> = Vec::new();
+
+        while !next.is_empty() {
+            std::mem::swap(&mut next, &mut current);
+
+            for (corner_class_index, twist) in current.drain(..) {
+                let corners = corners_source.corner_rep[corner_class_index as usize];
+                for m in Move::ALLMOVES {
+                    let corners_after_move = moves_source.get_corners(corners, m);
+                    let twist_after_move = moves_source.get_twist(twist, m);
+
+                    let corner_class_index_after_move =
+                        corners_source.corner_class_index[corners_after_move as usize];
+                    let corner_symmetry_after_move =
+                        corners_source.corner_symmetry[corners_after_move as usize];
+
+                    let twist_conj =
+                        moves_source.get_twist_conj(twist_after_move, corner_symmetry_after_move);
+
+                    let idx1 =
+                        (NTWIST * (corner_class_index_after_move as usize)) + twist_conj as usize;
+
+                    if table[idx1] == u8::MAX {
+                        //this is the first time we have reached this point
+                        table[idx1] = depth + 1;
+                        next.push((corner_class_index_after_move, twist_conj));
+
+                        //The representative may be symmetric, in which case the conjugated twists are equivalent
+                        let mut sym = c_sym[corner_class_index_after_move as usize];
+                        if sym != 1 {
+                            for j in 1..16 {
+                                sym >>= 1;
+                                if sym % 2 == 1 {
+                                    let twist_conj_and_symmetry =
+                                        moves_source.get_twist_conj(twist_conj, j);
+
+                                    let idx2 = (NTWIST * (corner_class_index_after_move as usize))
+                                        + twist_conj_and_symmetry as usize;
+
+                                    if table[idx2] == u8::MAX {
+                                        table[idx2] = depth + 1;
+                                        next.push((
+                                            corner_class_index_after_move,
+                                            twist_conj_and_symmetry,
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            depth += 1;
+        }
+
+        table
+    }
+
+    pub fn get_corner_twist_depth(&self, table: &[u8], corners: u16, twist: u16) -> u8 {
+        let corner_class_index = self.corners_source.corner_class_index[corners as usize];
+        let corner_sym = self.corners_source.corner_symmetry[corners as usize];
+
+        let twist_conj = self.moves_source.get_twist_conj(twist, corner_sym);
+
+        table[(NTWIST * (corner_class_index as usize)) + twist_conj as usize]
+    }
+}
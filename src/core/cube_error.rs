@@ -0,0 +1,168 @@
+use crate::core::prelude::*;
+
+use itertools::Itertools;
+
+/// Reasons why a cube is not valid, naming the pieces and facelets involved
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone)]
+pub enum CubeError {
+    /// These facelets have not been painted
+    UnsetFacelets(Vec<FaceletPosition>),
+    /// A color appears the wrong number of times
+    WrongColorCount { color: FaceColor, count: usize },
+    /// The corner in this position has no up or down colored facelet
+    CornerWithoutUpOrDown { position: CornerPosition },
+    /// The colors of the corner in this position, in clockwise order, do not match any corner
+    UnknownCorner {
+        position: CornerPosition,
+        colors: [FaceColor; 3],
+    },
+    /// The colors of the edge in this position do not match any edge
+    UnknownEdge {
+        position: EdgePosition,
+        colors: [FaceColor; 2],
+    },
+    /// The same corner is in more than one position
+    DuplicateCorner {
+        corner: CornerPosition,
+        positions: Vec<CornerPosition>,
+    },
+    /// The same edge is in more than one position
+    DuplicateEdge {
+        edge: EdgePosition,
+        positions: Vec<EdgePosition>,
+    },
+    /// An odd number of edges are flipped. Flipping the edge in this position would fix it.
+    EdgeFlip { position: EdgePosition },
+    /// The total corner twist is wrong. Twisting the corner in this position would fix it.
+    CornerTwist {
+        position: CornerPosition,
+        clockwise: bool,
+    },
+    /// The edge and corner permutations have different parities. Swapping these edges would fix it.
+    Parity { swap: [EdgePosition; 2] },
+}
+
+impl CubeError {
+    /// The facelets which should be highlighted to show the problem
+    pub fn get_facelets(&self) -> Vec<FaceletPosition> {
+        match self {
+            CubeError::UnsetFacelets(facelets) => facelets.clone(),
+            CubeError::WrongColorCount { .. } => vec![],
+            CubeError::CornerWithoutUpOrDown { position }
+            | CubeError::UnknownCorner { position, .. }
+            | CubeError::CornerTwist { position, .. } => {
+                CornerPosition::CORNERFACELETS[*position as usize].to_vec()
+            }
+            CubeError::UnknownEdge { position, .. } | CubeError::EdgeFlip { position } => {
+                EdgePosition::EDGEFACELETS[*position as usize].to_vec()
+            }
+            CubeError::DuplicateCorner { positions, .. } => positions
+                .iter()
+                .flat_map(|p| CornerPosition::CORNERFACELETS[*p as usize])
+                .collect_vec(),
+            CubeError::DuplicateEdge { positions, .. } => positions
+                .iter()
+                .flat_map(|p| EdgePosition::EDGEFACELETS[*p as usize])
+                .collect_vec(),
+            CubeError::Parity { swap } => swap
+                .iter()
+                .flat_map(|p| EdgePosition::EDGEFACELETS[*p as usize])
+                .collect_vec(),
+        }
+    }
+
+    /// A minimal change which would fix this problem
+    pub fn get_suggested_fix(&self) -> String {
+        match self {
+            CubeError::UnsetFacelets(_) => "Paint the remaining facelets".to_string(),
+            CubeError::WrongColorCount { color, count } if *count > 9 => {
+                format!("Repaint {} {} facelets", count - 9, color)
+            }
+            CubeError::WrongColorCount { color, count } => {
+                format!("Paint {} more {} facelets", 9 - count, color)
+            }
+            CubeError::CornerWithoutUpOrDown { position } => format!(
+                "Repaint one facelet of the {} corner {} or {}",
+                position,
+                FaceColor::Up,
+                FaceColor::Down
+            ),
+            CubeError::UnknownCorner { position, .. } => {
+                format!(
+                    "Repaint the {} corner so its colors match a corner",
+                    position
+                )
+            }
+            CubeError::UnknownEdge { position, .. } => {
+                format!("Repaint the {} edge so its colors match an edge", position)
+            }
+            CubeError::DuplicateCorner { corner, positions } => format!(
+                "Only one of the corners at {} can be {}",
+                positions.iter().join(", "),
+                corner
+            ),
+            CubeError::DuplicateEdge { edge, positions } => format!(
+                "Only one of the edges at {} can be {}",
+                positions.iter().join(", "),
+                edge
+            ),
+            CubeError::EdgeFlip { position } => format!("Flip the {} edge", position),
+            CubeError::CornerTwist {
+                position,
+                clockwise,
+            } => format!(
+                "Twist the {} corner {}",
+                position,
+                if *clockwise {
+                    "clockwise"
+                } else {
+                    "anticlockwise"
+                }
+            ),
+            CubeError::Parity { swap } => format!("Swap the {} and {} edges", swap[0], swap[1]),
+        }
+    }
+}
+
+impl std::fmt::Display for CubeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CubeError::UnsetFacelets(facelets) => {
+                write!(f, "{} facelets are not set", facelets.len())
+            }
+            CubeError::WrongColorCount { color, count } => {
+                write!(f, "{} appears {} times", color, count)
+            }
+            CubeError::CornerWithoutUpOrDown { position } => write!(
+                f,
+                "The {} corner has no {} or {} facelet",
+                position,
+                FaceColor::Up,
+                FaceColor::Down
+            ),
+            CubeError::UnknownCorner { position, colors } => write!(
+                f,
+                "The {} corner is {}, which is not a corner",
+                position,
+                colors.iter().join("-")
+            ),
+            CubeError::UnknownEdge { position, colors } => write!(
+                f,
+                "The {} edge is {}, which is not an edge",
+                position,
+                colors.iter().join("-")
+            ),
+            CubeError::DuplicateCorner { corner, positions } => {
+                write!(f, "The {} corner appears {} times", corner, positions.len())
+            }
+            CubeError::DuplicateEdge { edge, positions } => {
+                write!(f, "The {} edge appears {} times", edge, positions.len())
+            }
+            CubeError::EdgeFlip { .. } => write!(f, "One edge is flipped"),
+            CubeError::CornerTwist { .. } => write!(f, "One corner is twisted"),
+            CubeError::Parity { .. } => write!(f, "Two edges are swapped"),
+        }
+    }
+}
+
+impl std::error::Error for CubeError {}
@@ -173,32 +173,63 @@ impl CubieCube {
         }
     }
 
-    pub fn verify(&self) -> Result<(), &str> {
-        let unique_edges = self.edge_positions.into_iter().dedup().count();
-        if unique_edges < EdgePosition::COUNT {
-            return Err("There are duplicate edges");
+    pub fn verify(&self) -> Result<(), CubeError> {
+        for corner in CornerPosition::DEFAULT_ARRAY {
+            let positions = CornerPosition::DEFAULT_ARRAY
+                .into_iter()
+                .filter(|&p| self.corner_positions[p as usize] == corner)
+                .collect_vec();
+            if positions.len() > 1 {
+                return Err(CubeError::DuplicateCorner { corner, positions });
+            }
         }
 
-        let unique_corners = self.corner_positions.into_iter().dedup().count();
-        if unique_corners < CornerPosition::COUNT {
-            return Err("There are duplicate corners");
+        for edge in EdgePosition::DEFAULT_ARRAY {
+            let positions = EdgePosition::DEFAULT_ARRAY
+                .into_iter()
+                .filter(|&p| self.edge_positions[p as usize] == edge)
+                .collect_vec();
+            if positions.len() > 1 {
+                return Err(CubeError::DuplicateEdge { edge, positions });
+            }
         }
 
         let edge_flip: u8 = self.edge_orientations.into_iter().map(|x| x as u8).sum();
         if edge_flip % 2 != 0 {
-            return Err("Total Edge flip is wrong");
+            //Any flipped edge can be flipped back
+            let position = EdgePosition::DEFAULT_ARRAY
+                .into_iter()
+                .find(|&p| self.edge_orientations[p as usize] != EdgeOrientation::Zero)
+                .unwrap();
+            return Err(CubeError::EdgeFlip { position });
         }
 
         let corner_twist: u8 = self.corner_orientations.into_iter().map(|x| x as u8).sum();
         if corner_twist % 3 != 0 {
-            return Err("Total Corner twist is wrong");
+            let position = CornerPosition::DEFAULT_ARRAY
+                .into_iter()
+                .find(|&p| self.corner_orientations[p as usize] != CornerOrientation::Zero)
+                .unwrap();
+            //Twisting clockwise increases the orientation by one
+            return Err(CubeError::CornerTwist {
+                position,
+                clockwise: corner_twist % 3 == 2,
+            });
         }
 
         let edge_parity = self.get_edge_parity();
         let corner_parity = self.get_corner_parity();
 
         if edge_parity != corner_parity {
-            return Err("Edge and corner parities are not equal");
+            //Swapping any two edges fixes the parity, so prefer edges which are out of place
+            let mut misplaced = EdgePosition::DEFAULT_ARRAY
+                .into_iter()
+                .filter(|&p| self.edge_positions[p as usize] != p);
+            let swap = match (misplaced.next(), misplaced.next()) {
+                (Some(a), Some(b)) => [a, b],
+                _ => [EdgePosition::Ur, EdgePosition::Uf],
+            };
+            return Err(CubeError::Parity { swap });
         }
 
         Ok(())
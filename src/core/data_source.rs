@@ -1,7 +1,9 @@
 use crate::core::prelude::*;
 
-//#[derive(BorshSerialize, BorshDeserialize)]
 pub struct DataSource {
+    ///Whether the phase 1 pruning table was only partially generated
+    pub quick: bool,
+
     ///Indicates the minimum number of phase 2 moves required to solve the corners and slice. Indexed by corners * 24 + slice
     pub corner_slice_depth: Vec<u8>,
 
@@ -30,15 +32,25 @@ pub struct FlipSliceSource {
 
 impl DataSource {
     pub fn create(quick: bool) -> Self {
-        let moves_source = MovesSource::create();
-        let corners_source = CornerSymmetriesSource::create();
-        let flip_slice_source = FlipSliceSource::create();
-        let phase_2_edge_merge = Self::create_phase_2_edge_merge();
-        let corner_slice_depth = Self::create_corner_slice_depth(&moves_source);
-        let phase_2_pruning = Self::create_phase_2_pruning(&moves_source, &corners_source);
-        let phase_1_pruning = Self::create_phase_1_pruning(quick, &moves_source, &flip_slice_source);
-
-        Self {
+        Self::create_with_observer(quick, &mut IgnoreProgress).expect("Generation can not be cancelled")
+    }
+
+    /// Creates the solve data, reporting progress to the observer, which may cancel it
+    pub fn create_with_observer(
+        quick: bool,
+        observer: &mut impl GenerationObserver,
+    ) -> Result<Self, GenerationCancelled> {
+        let moves_source = MovesSource::create(observer)?;
+        let corners_source = CornerSymmetriesSource::create(observer)?;
+        let flip_slice_source = FlipSliceSource::create(observer)?;
+        let phase_2_edge_merge = Self::create_phase_2_edge_merge(observer)?;
+        let corner_slice_depth = Self::create_corner_slice_depth(&moves_source, observer)?;
+        let phase_2_pruning = Self::create_phase_2_pruning(&moves_source, &corners_source, observer)?;
+        let phase_1_pruning =
+            Self::create_phase_1_pruning(quick, &moves_source, &flip_slice_source, observer)?;
+
+        Ok(Self {
+            quick,
             moves_source,
             corner_slice_depth,
             corners_source,
@@ -46,7 +58,7 @@ impl DataSource {
             phase_2_edge_merge,
             phase_1_pruning,
             phase_2_pruning,
-        }
+        })
     }
 
     pub fn get_flip_slice_twist_depth_mod_3(&self, flip: u16, twist: u16, slice_sorted: u16) -> Option<u8> {
@@ -68,6 +80,36 @@ impl DataSource {
         else {Some(r as u8)}        
     }
 
+    /// Gets the exact number of moves needed to reach phase 2, by following the mod 3 depths down to zero.
+    /// Returns None if the depth is not in the table, which can happen if it was created in quick mode.
+    pub fn get_phase_1_depth(&self, flip: u16, twist: u16, slice_sorted: u16) -> Option<u8> {
+        let mut flip = flip;
+        let mut twist = twist;
+        let mut slice_sorted = slice_sorted;
+        let mut depth = 0;
+
+        while flip != 0 || twist != 0 || slice_sorted / 24 != 0 {
+            let depth_mod3 = self.get_flip_slice_twist_depth_mod_3(flip, twist, slice_sorted)?;
+            let target = (depth_mod3 + 2) % 3;
+
+            //There is always a move one closer unless the table is inconsistent, in which case the depth is unknown
+            let m = Move::ALLMOVES.into_iter().find(|&m| {
+                self.get_flip_slice_twist_depth_mod_3(
+                    self.moves_source.get_flip(flip, m),
+                    self.moves_source.get_twist(twist, m),
+                    self.moves_source.get_slice_sorted(slice_sorted, m),
+                ) == Some(target)
+            })?;
+
+            flip = self.moves_source.get_flip(flip, m);
+            twist = self.moves_source.get_twist(twist, m);
+            slice_sorted = self.moves_source.get_slice_sorted(slice_sorted, m);
+            depth += 1;
+        }
+
+        Some(depth)
+    }
+
     pub fn get_ud_edges(&self, u_edges: u16, d_edges: u16) -> u16 {
         let index = 24 * u_edges + (d_edges % 24);
 
@@ -133,7 +175,6 @@ impl DataSource {
     }
 }
 
-//#[derive(BorshSerialize, BorshDeserialize)]
 pub struct MovesSource {
     pub twist_move: Vec<u16>,
     pub flip_move: Vec<u16>,
@@ -148,19 +189,38 @@ pub struct MovesSource {
 }
 
 impl MovesSource {
-    pub fn create() -> Self {
+    /// The number of tables which make up the moves source
+    const TABLES: usize = 9;
+
+    pub fn create(observer: &mut impl GenerationObserver) -> Result<Self, GenerationCancelled> {
+        let mut report = |done: usize| {
+            report_progress(
+                observer,
+                GenerationProgress::step(GenerationTable::Moves, done, Self::TABLES),
+            )
+        };
+
         let corners_move = CornersProperty::create(&CornersProperty {});
+        report(1)?;
         let twist_move = TwistProperty::create(&TwistProperty {});
+        report(2)?;
         let flip_move = FlipProperty::create(&FlipProperty {});
+        report(3)?;
         let slice_sorted_move = SliceSortedProperty::create(&SliceSortedProperty {});
+        report(4)?;
         let u_edges_move = UpEdgesProperty::create(&UpEdgesProperty {});
+        report(5)?;
         let d_edges_move = DownEdgesProperty::create(&DownEdgesProperty {});
+        report(6)?;
         let u_d_edges_move = UpDownEdgesProperty::create(&UpDownEdgesProperty {});
+        report(7)?;
 
         let u_d_edges_conjugation = DataSource::create_up_down_edges_conjugation();
+        report(8)?;
         let twist_conjugation = DataSource::create_twist_conjugation();
+        report(9)?;
 
-        Self {
+        Ok(Self {
             twist_move,
             flip_move,
             slice_sorted_move,
@@ -170,7 +230,7 @@ impl MovesSource {
             corners_move,
             u_d_edges_conjugation,
             twist_conjugation,
-        }
+        })
     }
 
     pub fn get_ud_edge(&self, prev: u16, m: usize) -> u16 {
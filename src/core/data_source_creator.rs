@@ -1,7 +1,9 @@
 use crate::core::prelude::*;
 
 impl FlipSliceSource {
-    pub fn create() -> FlipSliceSource {
+    pub fn create(
+        observer: &mut impl GenerationObserver,
+    ) -> Result<FlipSliceSource, GenerationCancelled> {
         let mut flip_slice_class_index = vec![u16::MAX; 2048 * 495];
         let mut flip_slice_symmetry = vec![u8::MIN; 2048 * 495];
         let mut flip_slice_rep = [u32::MIN; 64430];
@@ -9,12 +11,16 @@ impl FlipSliceSource {
         let mut class_index: usize = 0;
         let mut cube = CubieCube::default();
         for slice in 0..495 {
+            report_progress(
+                observer,
+                GenerationProgress::step(GenerationTable::FlipSliceSymmetries, slice, 495),
+            )?;
             cube.set_slice(slice as u16);
 
             for flip in 0..2048 {
                 cube.set_flip(flip);
 
-                let index = (2048 * (slice as usize)) + (flip as usize);
+                let index = (2048 * slice) + (flip as usize);
 
                 if flip_slice_class_index[index] == u16::MAX {
                     flip_slice_class_index[index] = class_index as u16;
@@ -38,16 +44,23 @@ impl FlipSliceSource {
             }
         }
 
-        FlipSliceSource {
+        report_progress(
+            observer,
+            GenerationProgress::step(GenerationTable::FlipSliceSymmetries, 495, 495),
+        )?;
+
+        Ok(FlipSliceSource {
             flip_slice_class_index,
             flip_slice_symmetry,
             flip_slice_rep: flip_slice_rep.into(),
-        }
+        })
     }
 }
 
 impl CornerSymmetriesSource {
-    pub fn create() -> CornerSymmetriesSource {
+    pub fn create(
+        observer: &mut impl GenerationObserver,
+    ) -> Result<CornerSymmetriesSource, GenerationCancelled> {
         let mut corner_class_index = [u16::MAX; 40320];
         let mut corner_symmetry = [u8::MIN; 40320];
         let mut corner_rep = [u16::MIN; 2768];
@@ -55,6 +68,12 @@ impl CornerSymmetriesSource {
         let mut class_idx: usize = 0;
         let mut cube = CubieCube::default();
         for cp in 0..40320 {
+            if cp % 4096 == 0 {
+                report_progress(
+                    observer,
+                    GenerationProgress::step(GenerationTable::CornerSymmetries, cp, 40320),
+                )?;
+            }
             cube.set_corners(cp as u16);
 
             if corner_class_index[cp] == u16::MAX {
@@ -79,17 +98,26 @@ impl CornerSymmetriesSource {
             class_idx += 1;
         }
 
-        CornerSymmetriesSource {
+        report_progress(
+            observer,
+            GenerationProgress::step(GenerationTable::CornerSymmetries, 40320, 40320),
+        )?;
+
+        Ok(CornerSymmetriesSource {
             corner_class_index: corner_class_index.into(),
             corner_symmetry: corner_symmetry.into(),
             corner_rep: corner_rep.into(),
-        }
+        })
     }
 }
 
 impl DataSource {
-    pub fn create_corner_slice_depth(moves_source: &MovesSource) -> Vec<u8> {
-        let mut table = vec![u8::MAX; 40320 * 24];
+    pub fn create_corner_slice_depth(
+        moves_source: &MovesSource,
+        observer: &mut impl GenerationObserver,
+    ) -> Result<Vec<u8>, GenerationCancelled> {
+        let total = 40320 * 24;
+        let mut table = vec![u8::MAX; total];
 
         table[0] = 0;
         let mut done = 1;
@@ -97,7 +125,7 @@ impl DataSource {
 
         let mut next: Vec<(usize, usize)> = vec![(0, 0)];
 
-        while done < 40320 * 24 {
+        while done < total {
             let mut next_next: Vec<(usize, usize)> = Vec::new();
             for (corners, slice) in next {
                 for m in Move::PHASE2MOVES {
@@ -105,7 +133,7 @@ impl DataSource {
                     let slice1 = moves_source.slice_sorted_move[(18 * slice) + m as usize] as usize;
                     let idx1 = 24 * corners1 + slice1;
 
-                    assert!(idx1 < 40320 * 24);
+                    assert!(idx1 < total);
 
                     if table[idx1] == u8::MAX {
                         //this is the first time we have reached this point
@@ -117,8 +145,18 @@ impl DataSource {
             }
             depth += 1;
             next = next_next;
+
+            report_progress(
+                observer,
+                GenerationProgress {
+                    table: GenerationTable::CornerSliceDepth,
+                    depth: depth as u32,
+                    done,
+                    total,
+                },
+            )?;
         }
 
-        table
+        Ok(table)
     }
 }
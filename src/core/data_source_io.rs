@@ -0,0 +1,318 @@
+use std::io::{Read, Write};
+
+use crate::core::prelude::*;
+
+#[derive(Debug)]
+pub enum DataSourceReadError {
+    Io(std::io::Error),
+    /// The file does not start with the expected magic bytes
+    NotADataFile,
+    /// The file was written by an incompatible version of the format
+    UnsupportedVersion(u32),
+    /// The file was written with different table dimensions
+    SizeMismatch {
+        name: &'static str,
+        expected: u32,
+        found: u32,
+    },
+    /// A table has the wrong number of entries
+    TableLengthMismatch {
+        name: &'static str,
+        expected: u64,
+        found: u64,
+    },
+    /// The contents of the file do not match the checksum
+    ChecksumMismatch,
+}
+
+impl std::fmt::Display for DataSourceReadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataSourceReadError::Io(e) => write!(f, "Could not read solve data: {}", e),
+            DataSourceReadError::NotADataFile => write!(f, "This is not a solve data file"),
+            DataSourceReadError::UnsupportedVersion(version) => {
+                write!(f, "Solve data format version {} is not supported", version)
+            }
+            DataSourceReadError::SizeMismatch {
+                name,
+                expected,
+                found,
+            } => write!(f, "{} should be {} but was {}", name, expected, found),
+            DataSourceReadError::TableLengthMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Table {} should have {} entries but had {}",
+                name, expected, found
+            ),
+            DataSourceReadError::ChecksumMismatch => write!(f, "Solve data is corrupted"),
+        }
+    }
+}
+
+impl std::error::Error for DataSourceReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DataSourceReadError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DataSourceReadError {
+    fn from(e: std::io::Error) -> Self {
+        DataSourceReadError::Io(e)
+    }
+}
+
+/// Table entries which can be written as little endian bytes
+trait TableEntry: Sized + Copy {
+    const SIZE: usize;
+    fn write_bytes(self, bytes: &mut Vec<u8>);
+    fn read_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_table_entry {
+    ($t:ty) => {
+        impl TableEntry for $t {
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn write_bytes(self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_bytes(bytes: &[u8]) -> Self {
+                <$t>::from_le_bytes(bytes.try_into().unwrap())
+            }
+        }
+    };
+}
+
+impl_table_entry!(u8);
+impl_table_entry!(u16);
+impl_table_entry!(u32);
+
+/// FNV-1a hash of everything written or read
+struct Checksum(u64);
+
+impl Checksum {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= b as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+struct DataWriter<W: Write> {
+    writer: W,
+    checksum: Checksum,
+}
+
+impl<W: Write> DataWriter<W> {
+    fn write_bytes(&mut self, bytes: &[u8]) -> std::io::Result<()> {
+        self.checksum.update(bytes);
+        self.writer.write_all(bytes)
+    }
+
+    fn write_table<T: TableEntry>(&mut self, table: &[T]) -> std::io::Result<()> {
+        self.write_bytes(&(table.len() as u64).to_le_bytes())?;
+
+        let mut bytes = Vec::with_capacity(table.len() * T::SIZE);
+        for &entry in table {
+            entry.write_bytes(&mut bytes);
+        }
+        self.write_bytes(&bytes)
+    }
+}
+
+struct DataReader<R: Read> {
+    reader: R,
+    checksum: Checksum,
+}
+
+impl<R: Read> DataReader<R> {
+    fn read_bytes<const N: usize>(&mut self) -> std::io::Result<[u8; N]> {
+        let mut bytes = [0u8; N];
+        self.reader.read_exact(&mut bytes)?;
+        self.checksum.update(&bytes);
+        Ok(bytes)
+    }
+
+    fn read_u32(&mut self) -> std::io::Result<u32> {
+        Ok(u32::from_le_bytes(self.read_bytes()?))
+    }
+
+    fn read_table<T: TableEntry>(
+        &mut self,
+        name: &'static str,
+        expected: usize,
+    ) -> Result<Vec<T>, DataSourceReadError> {
+        let found = u64::from_le_bytes(self.read_bytes()?);
+        if found != expected as u64 {
+            return Err(DataSourceReadError::TableLengthMismatch {
+                name,
+                expected: expected as u64,
+                found,
+            });
+        }
+
+        let mut bytes = vec![0u8; expected * T::SIZE];
+        self.reader.read_exact(&mut bytes)?;
+        self.checksum.update(&bytes);
+
+        Ok(bytes.chunks_exact(T::SIZE).map(T::read_bytes).collect())
+    }
+}
+
+impl DataSource {
+    const MAGIC: [u8; 4] = *b"PZCB";
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// The dimensions which the tables depend on, in the order they are written
+    const SIZES: [(&'static str, usize); 9] = [
+        ("NFLIPSLICECLASS", NFLIPSLICECLASS),
+        ("NTWIST", NTWIST),
+        ("NFLIP", NFLIP),
+        ("NSLICE", NSLICE),
+        ("NSLICESORTED", NSLICESORTED),
+        ("NUEDGESPHASE2", NUEDGESPHASE2),
+        ("NCORNERS", NCORNERS),
+        ("NCORNERSCLASS", NCORNERSCLASS),
+        ("NUDEDGES", NUDEDGES),
+    ];
+
+    /// Writes all of the tables in the following format. All numbers are little endian.
+    ///
+    /// - The magic bytes `PZCB`
+    /// - The format version as a u32
+    /// - The quick flag as a u8
+    /// - Each of the table dimensions (`NFLIPSLICECLASS`, `NTWIST`, ...) as a u32
+    /// - Each table as its number of entries (u64) followed by the entries
+    /// - The 64 bit FNV-1a hash of everything before it, as a u64
+    pub fn write_to(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let mut w = DataWriter {
+            writer,
+            checksum: Checksum::new(),
+        };
+
+        w.write_bytes(&Self::MAGIC)?;
+        w.write_bytes(&Self::FORMAT_VERSION.to_le_bytes())?;
+        w.write_bytes(&[self.quick as u8])?;
+        for (_, size) in Self::SIZES {
+            w.write_bytes(&(size as u32).to_le_bytes())?;
+        }
+
+        w.write_table(&self.corner_slice_depth)?;
+        w.write_table(&self.phase_2_pruning)?;
+        w.write_table(&self.phase_2_edge_merge)?;
+        w.write_table(&self.phase_1_pruning)?;
+
+        w.write_table(&self.flip_slice_source.flip_slice_class_index)?;
+        w.write_table(&self.flip_slice_source.flip_slice_symmetry)?;
+        w.write_table(&self.flip_slice_source.flip_slice_rep)?;
+
+        w.write_table(&self.corners_source.corner_class_index)?;
+        w.write_table(&self.corners_source.corner_symmetry)?;
+        w.write_table(&self.corners_source.corner_rep)?;
+
+        let ms = &self.moves_source;
+        w.write_table(&ms.twist_move)?;
+        w.write_table(&ms.flip_move)?;
+        w.write_table(&ms.slice_sorted_move)?;
+        w.write_table(&ms.u_edges_move)?;
+        w.write_table(&ms.d_edges_move)?;
+        w.write_table(&ms.u_d_edges_move)?;
+        w.write_table(&ms.corners_move)?;
+        w.write_table(&ms.u_d_edges_conjugation)?;
+        w.write_table(&ms.twist_conjugation)?;
+
+        let checksum = w.checksum.0;
+        w.writer.write_all(&checksum.to_le_bytes())?;
+        w.writer.flush()
+    }
+
+    /// Reads tables written by `write_to`, checking that they were written with the same dimensions and are not corrupted
+    pub fn read_from(reader: impl Read) -> Result<Self, DataSourceReadError> {
+        let mut r = DataReader {
+            reader,
+            checksum: Checksum::new(),
+        };
+
+        if r.read_bytes::<4>()? != Self::MAGIC {
+            return Err(DataSourceReadError::NotADataFile);
+        }
+
+        let version = r.read_u32()?;
+        if version != Self::FORMAT_VERSION {
+            return Err(DataSourceReadError::UnsupportedVersion(version));
+        }
+
+        let quick = r.read_bytes::<1>()?[0] != 0;
+
+        for (name, size) in Self::SIZES {
+            let found = r.read_u32()?;
+            if found != size as u32 {
+                return Err(DataSourceReadError::SizeMismatch {
+                    name,
+                    expected: size as u32,
+                    found,
+                });
+            }
+        }
+
+        let corner_slice_depth = r.read_table("corner_slice_depth", NCORNERS * NPERM4)?;
+        let phase_2_pruning = r.read_table("phase_2_pruning", NCORNERS * NCORNERSCLASS / 16)?;
+        let phase_2_edge_merge = r.read_table("phase_2_edge_merge", NPERM4 * NUEDGESPHASE2)?;
+        let phase_1_pruning =
+            r.read_table("phase_1_pruning", (NFLIPSLICECLASS * NTWIST / 16) + 1)?;
+
+        let flip_slice_source = FlipSliceSource {
+            flip_slice_class_index: r.read_table("flip_slice_class_index", NFLIP * NSLICE)?,
+            flip_slice_symmetry: r.read_table("flip_slice_symmetry", NFLIP * NSLICE)?,
+            flip_slice_rep: r.read_table("flip_slice_rep", NFLIPSLICECLASS)?,
+        };
+
+        let corners_source = CornerSymmetriesSource {
+            corner_class_index: r.read_table("corner_class_index", NCORNERS)?,
+            corner_symmetry: r.read_table("corner_symmetry", NCORNERS)?,
+            corner_rep: r.read_table("corner_rep", NCORNERSCLASS)?,
+        };
+
+        let moves_source = MovesSource {
+            twist_move: r.read_table("twist_move", NTWIST * 18)?,
+            flip_move: r.read_table("flip_move", NFLIP * 18)?,
+            slice_sorted_move: r.read_table("slice_sorted_move", NSLICESORTED * 18)?,
+            u_edges_move: r.read_table("u_edges_move", NSLICESORTED * 18)?,
+            d_edges_move: r.read_table("d_edges_move", NSLICESORTED * 18)?,
+            u_d_edges_move: r.read_table("u_d_edges_move", NUDEDGES * 10)?,
+            corners_move: r.read_table("corners_move", NCORNERS * 18)?,
+            u_d_edges_conjugation: r.read_table("u_d_edges_conjugation", NUDEDGES * 16)?,
+            twist_conjugation: r.read_table("twist_conjugation", NTWIST * 16)?,
+        };
+
+        let expected_checksum = r.checksum.0;
+        let mut checksum = [0u8; 8];
+        r.reader.read_exact(&mut checksum)?;
+        if u64::from_le_bytes(checksum) != expected_checksum {
+            return Err(DataSourceReadError::ChecksumMismatch);
+        }
+
+        Ok(Self {
+            quick,
+            corner_slice_depth,
+            phase_2_pruning,
+            phase_2_edge_merge,
+            phase_1_pruning,
+            flip_slice_source,
+            corners_source,
+            moves_source,
+        })
+    }
+}
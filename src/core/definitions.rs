@@ -224,6 +224,7 @@ impl From<FaceColor> for usize {
     FromRepr,
     EnumIter,
     EnumCount,
+    Hash,
 )]
 #[repr(u8)]
 pub enum MoveNumber {
@@ -0,0 +1,327 @@
+use crate::core::prelude::FaceColor::*;
+use crate::core::prelude::*;
+
+use itertools::Itertools;
+use strum::IntoEnumIterator;
+
+/// The layers turned by an extended move
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize, Copy, Clone, Hash,
+)]
+pub enum ExtendedMoveKind {
+    /// A single outer layer e.g. R
+    Face(FaceColor),
+    /// An outer layer and the adjacent middle slice e.g. Rw or r
+    Wide(FaceColor),
+    /// The middle slice, turning in the same direction as the given face.
+    /// M follows L, E follows D and S follows F
+    Slice(FaceColor),
+    /// The whole cube, turning in the same direction as the given face.
+    /// x follows R, y follows U and z follows F
+    Rotation(FaceColor),
+}
+
+/// A move which may turn a slice, two layers or the whole cube
+#[derive(
+    Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize, Copy, Clone, Hash,
+)]
+pub struct ExtendedMove {
+    pub kind: ExtendedMoveKind,
+    pub number: MoveNumber,
+}
+
+/// The orientation of the whole cube, as changed by rotations, slice moves and wide moves
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Copy, Clone, Hash)]
+pub struct CubeOrientation {
+    /// The centre currently at each face position, indexed by face position
+    pub centres: [FaceColor; 6],
+}
+
+impl Default for CubeOrientation {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl CubeOrientation {
+    pub const IDENTITY: CubeOrientation = CubeOrientation {
+        centres: [Up, Right, Front, Down, Left, Back],
+    };
+
+    /// The centre currently at a face position
+    pub fn get_centre(&self, position: FaceColor) -> FaceColor {
+        self.centres[position as usize]
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// The faces around an axis face, in the order they move during a clockwise turn of that face
+    const fn get_cycle(face: FaceColor) -> [FaceColor; 4] {
+        match face {
+            Right => [Front, Up, Back, Down],
+            Left => [Front, Down, Back, Up],
+            Up => [Front, Left, Back, Right],
+            Down => [Front, Right, Back, Left],
+            Front => [Up, Right, Down, Left],
+            Back => [Up, Left, Down, Right],
+        }
+    }
+
+    /// Rotate the whole cube in the same direction as a face turn
+    pub fn rotate(&self, face: FaceColor, number: MoveNumber) -> Self {
+        let cycle = Self::get_cycle(face);
+        let mut centres = self.centres;
+
+        for _ in 0..(number as usize) {
+            let previous = centres;
+            for i in 0..4 {
+                centres[cycle[(i + 1) % 4] as usize] = previous[cycle[i] as usize];
+            }
+        }
+
+        Self { centres }
+    }
+
+    /// The face turn, relative to the original centres, of turning the face currently at this position
+    pub fn get_move(&self, position: FaceColor, number: MoveNumber) -> Move {
+        Move::from((self.get_centre(position), number))
+    }
+
+    /// The rotations which return the cube to this orientation from the identity
+    pub fn get_rotations(&self) -> Vec<ExtendedMove> {
+        let rotations = [Right, Up, Front]
+            .into_iter()
+            .cartesian_product(MoveNumber::iter())
+            .map(|(face, number)| ExtendedMove {
+                kind: ExtendedMoveKind::Rotation(face),
+                number,
+            })
+            .collect_vec();
+
+        //There are only 24 orientations so a breadth first search of up to two rotations is sufficient
+        std::iter::once(vec![])
+            .chain(rotations.iter().map(|r| vec![*r]))
+            .chain(
+                rotations
+                    .iter()
+                    .cartesian_product(rotations.iter())
+                    .map(|(a, b)| vec![*a, *b]),
+            )
+            .find(|sequence| ExtendedMove::rewrite(sequence).1 == *self)
+            .unwrap()
+    }
+}
+
+impl ExtendedMove {
+    pub const fn face(face: FaceColor, number: MoveNumber) -> Self {
+        Self {
+            kind: ExtendedMoveKind::Face(face),
+            number,
+        }
+    }
+
+    const fn opposite(face: FaceColor) -> FaceColor {
+        FaceColor::from_repr((face as u8 + 3) % 6).unwrap()
+    }
+
+    const fn invert_number(number: MoveNumber) -> MoveNumber {
+        MoveNumber::from_repr(4 - number as u8).unwrap()
+    }
+
+    /// Gets the notation for this move e.g. R', Rw2, M or y'
+    pub fn get_notation(self) -> String {
+        let prefix = match self.kind {
+            ExtendedMoveKind::Face(face) => face.get_notation_char().to_string(),
+            ExtendedMoveKind::Wide(face) => format!("{}w", face.get_notation_char()),
+            ExtendedMoveKind::Slice(face) => match face {
+                Left | Right => "M",
+                Down | Up => "E",
+                Front | Back => "S",
+            }
+            .to_string(),
+            ExtendedMoveKind::Rotation(face) => match face {
+                Right | Left => "x",
+                Up | Down => "y",
+                Front | Back => "z",
+            }
+            .to_string(),
+        };
+
+        //Slices and rotations are named after the face they follow, so moves given relative to the opposite face are reversed
+        let number = match self.kind {
+            ExtendedMoveKind::Slice(Right | Up | Back)
+            | ExtendedMoveKind::Rotation(Left | Down | Back) => Self::invert_number(self.number),
+            _ => self.number,
+        };
+
+        prefix + number.get_notation_suffix()
+    }
+
+    /// Formats a sequence of moves, separated by spaces
+    pub fn format_sequence(moves: &[ExtendedMove]) -> String {
+        moves.iter().map(|m| m.get_notation()).join(" ")
+    }
+
+    /// Parses a whitespace separated sequence of moves such as "M2 U Rw' x y2 r"
+    pub fn parse_sequence(text: &str) -> Result<Vec<ExtendedMove>, MoveParseError> {
+        parse_tokens(text, Self::parse_token)
+    }
+
+    fn parse_token(token: &str, column: usize) -> Result<ExtendedMove, MoveParseError> {
+        let mut chars = token.chars();
+
+        let first = chars.next().ok_or_else(|| MoveParseError::UnknownFace {
+            token: token.to_string(),
+            column,
+        })?;
+
+        let kind = if let Some(face) = FaceColor::from_notation_char(first) {
+            if chars.as_str().starts_with('w') {
+                chars.next();
+                ExtendedMoveKind::Wide(face)
+            } else {
+                ExtendedMoveKind::Face(face)
+            }
+        } else if let Some(face) = FaceColor::from_notation_char(first.to_ascii_uppercase()) {
+            ExtendedMoveKind::Wide(face)
+        } else {
+            match first {
+                'M' => ExtendedMoveKind::Slice(Left),
+                'E' => ExtendedMoveKind::Slice(Down),
+                'S' => ExtendedMoveKind::Slice(Front),
+                'x' => ExtendedMoveKind::Rotation(Right),
+                'y' => ExtendedMoveKind::Rotation(Up),
+                'z' => ExtendedMoveKind::Rotation(Front),
+                _ => {
+                    return Err(MoveParseError::UnknownFace {
+                        token: token.to_string(),
+                        column,
+                    })
+                }
+            }
+        };
+
+        let number = MoveNumber::from_notation_suffix(chars.as_str()).ok_or_else(|| {
+            MoveParseError::BadSuffix {
+                token: token.to_string(),
+                column,
+            }
+        })?;
+
+        Ok(ExtendedMove { kind, number })
+    }
+
+    /// Rewrite a sequence of extended moves as face turns of the original centres, plus the final orientation of the cube.
+    /// Applying the face turns to a `CubieCube` gives the same piece positions relative to the original centres.
+    pub fn rewrite(moves: &[ExtendedMove]) -> (Algorithm, CubeOrientation) {
+        let mut orientation = CubeOrientation::IDENTITY;
+        let mut result: Vec<Move> = Vec::new();
+
+        for m in moves {
+            match m.kind {
+                ExtendedMoveKind::Face(face) => {
+                    result.push(orientation.get_move(face, m.number));
+                }
+                ExtendedMoveKind::Wide(face) => {
+                    //Rw = L x
+                    result.push(orientation.get_move(Self::opposite(face), m.number));
+                    orientation = orientation.rotate(face, m.number);
+                }
+                ExtendedMoveKind::Slice(face) => {
+                    //M = L' R x'
+                    result.push(orientation.get_move(face, Self::invert_number(m.number)));
+                    result.push(orientation.get_move(Self::opposite(face), m.number));
+                    orientation = orientation.rotate(face, m.number);
+                }
+                ExtendedMoveKind::Rotation(face) => {
+                    orientation = orientation.rotate(face, m.number);
+                }
+            }
+        }
+
+        (result.into(), orientation)
+    }
+}
+
+impl From<Move> for ExtendedMove {
+    fn from(m: Move) -> Self {
+        Self::face(m.get_color(), m.into_move_number())
+    }
+}
+
+impl std::fmt::Display for ExtendedMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_notation())
+    }
+}
+
+impl std::str::FromStr for ExtendedMove {
+    type Err = MoveParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_token(s.trim(), 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ntest::test_case;
+
+    fn rewrite_text(text: &str) -> (Algorithm, CubeOrientation) {
+        ExtendedMove::rewrite(&ExtendedMove::parse_sequence(text).unwrap())
+    }
+
+    #[test_case("y R y'", "B")]
+    #[test_case("x U x'", "F")]
+    #[test_case("z R z'", "U")]
+    #[test_case("M x", "L' R")]
+    #[test_case("Rw x'", "L")]
+    #[test_case("r x'", "L")]
+    #[test_case("E2 y2", "D2 U2")]
+    fn test_rewrite(text: &str, expected: &str) {
+        let (algorithm, orientation) = rewrite_text(text);
+
+        assert_eq!(algorithm.to_string(), expected);
+        assert!(orientation.is_identity());
+    }
+
+    #[test_case("r", "R M'")]
+    #[test_case("x", "R M' L'")]
+    #[test_case("u", "U E'")]
+    #[test_case("f", "F S")]
+    #[test_case("y", "U E' D'")]
+    #[test_case("z", "F S B'")]
+    #[test_case("M2 U M2 U2 M2 U M2", "L2 R2 D L2 R2 U2 L2 R2 D L2 R2")]
+    fn test_equivalent(text1: &str, text2: &str) {
+        let (algorithm1, orientation1) = rewrite_text(text1);
+        let (algorithm2, orientation2) = rewrite_text(text2);
+
+        let cube = CubieCube::random_cube(1);
+
+        assert_eq!(algorithm1.apply(&cube), algorithm2.apply(&cube));
+        assert_eq!(orientation1, orientation2);
+    }
+
+    #[test]
+    fn test_notation_round_trip() {
+        let text = "R Rw' r2 M E' S2 x y' z2 Uw";
+        let moves = ExtendedMove::parse_sequence(text).unwrap();
+        assert_eq!(
+            ExtendedMove::format_sequence(&moves),
+            "R Rw' Rw2 M E' S2 x y' z2 Uw"
+        );
+    }
+
+    #[test]
+    fn test_get_rotations() {
+        let (_, orientation) = rewrite_text("x y' z2 x");
+        let rotations = orientation.get_rotations();
+
+        assert!(rotations.len() <= 2);
+        assert_eq!(ExtendedMove::rewrite(&rotations).1, orientation);
+    }
+}
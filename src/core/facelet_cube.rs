@@ -33,16 +33,20 @@ impl FaceletCube {
     };
 
     ///checks that there is the correct number of each color
-    pub fn validate_colors(&self) -> Result<(), String> {
-        let counts = self.facelets.into_iter().counts_by(|f| f);
-
-        for c in counts {
-            if let Some(color) = c.0 {
-                if c.1 != 9 {
-                    return Err(format!("{} appears {} times.", color, c.1));
-                }
-            } else {
-                return Err("Not all colors set".to_string());
+    pub fn validate_colors(&self) -> Result<(), CubeError> {
+        let unset = FaceletPosition::iter()
+            .filter(|&p| self.facelets[p as usize].is_none())
+            .collect_vec();
+        if !unset.is_empty() {
+            return Err(CubeError::UnsetFacelets(unset));
+        }
+
+        let counts = self.facelets.into_iter().flatten().counts();
+
+        for color in FaceColor::iter() {
+            let count = counts.get(&color).copied().unwrap_or_default();
+            if count != 9 {
+                return Err(CubeError::WrongColorCount { color, count });
             }
         }
 
@@ -51,12 +55,10 @@ impl FaceletCube {
 }
 
 impl TryFrom<FaceletCube> for CubieCube {
-    type Error = String;
+    type Error = CubeError;
 
     fn try_from(cube: FaceletCube) -> Result<Self, Self::Error> {
-        if let Err(e) = cube.validate_colors() {
-            return Err(e);
-        }
+        cube.validate_colors()?;
 
         let mut edge_positions = [EdgePosition::Ur; 12];
         let mut corner_positions = [CornerPosition::Urf; 8];
@@ -66,23 +68,21 @@ impl TryFrom<FaceletCube> for CubieCube {
 
         for c in CornerPosition::DEFAULT_ARRAY {
             let fac = CornerPosition::CORNERFACELETS[c as usize];
+            let colors = fac.map(|f| cube.facelets[f as usize].unwrap());
             let ori = (0..3)
-                .find(|&i: &usize| {
-                    cube.facelets[fac[i] as usize] == Some(FaceColor::Up)
-                        || cube.facelets[fac[i] as usize] == Some(FaceColor::Down)
-                })
-                .ok_or("Bad Corner")?;
+                .find(|&i: &usize| colors[i] == FaceColor::Up || colors[i] == FaceColor::Down)
+                .ok_or(CubeError::CornerWithoutUpOrDown { position: c })?;
 
-            let col1 = cube.facelets[fac[(ori + 1) % 3] as usize];
-            let col2 = cube.facelets[fac[(ori + 2) % 3] as usize];
+            let col1 = colors[(ori + 1) % 3];
+            let col2 = colors[(ori + 2) % 3];
 
             let j = CornerPosition::DEFAULT_ARRAY
                 .into_iter()
                 .find(|&j| {
                     let col = CornerPosition::CORNERCOLORS[j as usize];
-                    col1 == Some(col[1]) && col2 == Some(col[2])
+                    colors[ori] == col[0] && col1 == col[1] && col2 == col[2]
                 })
-                .ok_or("Bad Corner")?;
+                .ok_or(CubeError::UnknownCorner { position: c, colors })?;
 
             corner_positions[c as usize] = j;
             corner_orientations[c as usize] = CornerOrientation::from_repr(ori as u8).unwrap();
@@ -90,28 +90,23 @@ impl TryFrom<FaceletCube> for CubieCube {
 
         for e in EdgePosition::DEFAULT_ARRAY {
             let fac = EdgePosition::EDGEFACELETS[e as usize];
+            let colors = fac.map(|f| cube.facelets[f as usize].unwrap());
 
             let j = EdgePosition::DEFAULT_ARRAY
                 .into_iter()
                 .filter_map(|j| {
                     let c = EdgePosition::EDGECOLORS[j as usize];
-                    let c0 = c[0];
-                    let c1 = c[1];
 
-                    if cube.facelets[fac[0] as usize] == Some(c0)
-                        && cube.facelets[fac[1] as usize] == Some(c1)
-                    {
+                    if colors == c {
                         Some((j, 0))
-                    } else if cube.facelets[fac[0] as usize] == Some(c1)
-                        && cube.facelets[fac[1] as usize] == Some(c0)
-                    {
+                    } else if colors == [c[1], c[0]] {
                         Some((j, 1))
                     } else {
                         None
                     }
                 })
                 .next()
-                .ok_or("bad edge")?;
+                .ok_or(CubeError::UnknownEdge { position: e, colors })?;
 
             edge_positions[e as usize] = j.0;
             edge_orientations[e as usize] = EdgeOrientation::from_repr(j.1).unwrap();
@@ -175,3 +170,111 @@ impl Default for FaceletCube {
         CubieCube::default().into()
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FaceletParseError {
+    /// There must be exactly 54 facelets
+    WrongLength(usize),
+    /// This letter does not correspond to any face. Index is 0-based.
+    UnknownLetter { letter: char, index: usize },
+}
+
+impl std::fmt::Display for FaceletParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FaceletParseError::WrongLength(length) => {
+                write!(f, "Expected 54 facelets but found {}", length)
+            }
+            FaceletParseError::UnknownLetter { letter, index } => {
+                write!(f, "Unknown letter '{}' at facelet {}", letter, index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FaceletParseError {}
+
+/// The letter used for each face when reading and writing facelet strings, indexed by FaceColor.
+/// Unset facelets are written as '.'
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone, Copy)]
+pub struct ColorScheme {
+    letters: [char; 6],
+}
+
+impl ColorScheme {
+    /// The face letters, as used by Kociemba e.g. UUUUUUUUURRRRRRRRRFFFFFFFFFDDDDDDDDDLLLLLLLLLBBBBBBBBB
+    pub const FACES: ColorScheme = ColorScheme {
+        letters: ['U', 'R', 'F', 'D', 'L', 'B'],
+    };
+
+    /// The colors shown in the app - yellow up, green right, red front, white down, blue left and orange back
+    pub const COLORS: ColorScheme = ColorScheme {
+        letters: ['Y', 'G', 'R', 'W', 'B', 'O'],
+    };
+
+    const UNSET: char = '.';
+
+    /// Create a scheme from the letters for Up, Right, Front, Down, Left and Back.
+    /// Returns None unless the letters are distinct.
+    pub fn new(letters: [char; 6]) -> Option<Self> {
+        let letters = letters.map(|c| c.to_ascii_uppercase());
+        if letters.iter().all_unique() && !letters.contains(&Self::UNSET) {
+            Some(Self { letters })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_letter(&self, face: FaceColor) -> char {
+        self.letters[face as usize]
+    }
+
+    pub fn get_face(&self, letter: char) -> Option<FaceColor> {
+        let letter = letter.to_ascii_uppercase();
+        self.letters
+            .iter()
+            .position(|&c| c == letter)
+            .and_then(|i| FaceColor::from_repr(i as u8))
+    }
+
+    pub fn parse(&self, text: &str) -> Result<FaceletCube, FaceletParseError> {
+        let text = text.trim();
+        let length = text.chars().count();
+        if length != 54 {
+            return Err(FaceletParseError::WrongLength(length));
+        }
+
+        let mut facelets = [None; 54];
+        for (index, letter) in text.chars().enumerate() {
+            if letter != Self::UNSET {
+                facelets[index] = Some(
+                    self.get_face(letter)
+                        .ok_or(FaceletParseError::UnknownLetter { letter, index })?,
+                );
+            }
+        }
+
+        Ok(FaceletCube { facelets })
+    }
+
+    pub fn format(&self, cube: &FaceletCube) -> String {
+        cube.facelets
+            .iter()
+            .map(|f| f.map_or(Self::UNSET, |face| self.get_letter(face)))
+            .collect()
+    }
+}
+
+impl std::str::FromStr for FaceletCube {
+    type Err = FaceletParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ColorScheme::FACES.parse(s)
+    }
+}
+
+impl std::fmt::Display for FaceletCube {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", ColorScheme::FACES.format(self))
+    }
+}
@@ -0,0 +1,135 @@
+/// The tables which make up the solve data, in the order they are generated
+#[derive(Debug, PartialEq, Eq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub enum GenerationTable {
+    Moves,
+    CornerSymmetries,
+    FlipSliceSymmetries,
+    Phase2EdgeMerge,
+    CornerSliceDepth,
+    Phase2Pruning,
+    Phase1Pruning,
+}
+
+impl GenerationTable {
+    pub const ALL: [GenerationTable; 7] = [
+        GenerationTable::Moves,
+        GenerationTable::CornerSymmetries,
+        GenerationTable::FlipSliceSymmetries,
+        GenerationTable::Phase2EdgeMerge,
+        GenerationTable::CornerSliceDepth,
+        GenerationTable::Phase2Pruning,
+        GenerationTable::Phase1Pruning,
+    ];
+}
+
+impl std::fmt::Display for GenerationTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            GenerationTable::Moves => "moves",
+            GenerationTable::CornerSymmetries => "corner symmetries",
+            GenerationTable::FlipSliceSymmetries => "flip slice symmetries",
+            GenerationTable::Phase2EdgeMerge => "phase 2 edge merge",
+            GenerationTable::CornerSliceDepth => "corner slice depth",
+            GenerationTable::Phase2Pruning => "phase 2 pruning",
+            GenerationTable::Phase1Pruning => "phase 1 pruning",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// How far the generation of one table has got
+#[derive(Debug, PartialEq, Eq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct GenerationProgress {
+    pub table: GenerationTable,
+    /// The depth the breadth first search has reached
+    pub depth: u32,
+    /// The number of states which have been filled in
+    pub done: usize,
+    /// The number of states which will have been filled in when the table is finished.
+    /// Tables made in quick mode, and the phase 2 pruning table, stop before every state in the table is filled in.
+    pub total: usize,
+}
+
+impl GenerationProgress {
+    /// Progress through a table which is not made by a breadth first search
+    pub fn step(table: GenerationTable, done: usize, total: usize) -> Self {
+        Self {
+            table,
+            depth: 0,
+            done,
+            total,
+        }
+    }
+
+    /// The fraction of this table which has been filled in
+    pub fn fraction(&self) -> f64 {
+        self.done as f64 / self.total as f64
+    }
+
+    /// The fraction of all the tables which has been generated, counting each table equally
+    pub fn overall_fraction(&self) -> f64 {
+        let index = GenerationTable::ALL
+            .iter()
+            .position(|t| *t == self.table)
+            .unwrap_or_default();
+
+        (index as f64 + self.fraction()) / GenerationTable::ALL.len() as f64
+    }
+}
+
+/// What table generation should do after reporting progress
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GenerationControl {
+    Continue,
+    Cancel,
+}
+
+/// Receives progress while the solve data is generated and may cancel it
+pub trait GenerationObserver {
+    fn on_progress(&mut self, progress: GenerationProgress) -> GenerationControl;
+}
+
+impl<F: FnMut(GenerationProgress) -> GenerationControl> GenerationObserver for F {
+    fn on_progress(&mut self, progress: GenerationProgress) -> GenerationControl {
+        self(progress)
+    }
+}
+
+/// An observer which never cancels
+pub struct IgnoreProgress;
+
+impl GenerationObserver for IgnoreProgress {
+    fn on_progress(&mut self, _progress: GenerationProgress) -> GenerationControl {
+        GenerationControl::Continue
+    }
+}
+
+/// Table generation was cancelled by the observer
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct GenerationCancelled {
+    pub table: GenerationTable,
+}
+
+impl std::fmt::Display for GenerationCancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Generation was cancelled while making the {} table",
+            self.table
+        )
+    }
+}
+
+impl std::error::Error for GenerationCancelled {}
+
+pub(crate) fn report_progress(
+    observer: &mut impl GenerationObserver,
+    progress: GenerationProgress,
+) -> Result<(), GenerationCancelled> {
+    match observer.on_progress(progress) {
+        GenerationControl::Continue => Ok(()),
+        GenerationControl::Cancel => Err(GenerationCancelled {
+            table: progress.table,
+        }),
+    }
+}
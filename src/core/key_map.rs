@@ -0,0 +1,94 @@
+use crate::core::prelude::*;
+
+/// Which keys turn which layers
+#[derive(PartialEq, Eq, Copy, Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
+pub enum KeyMap {
+    /// The keyboard does not turn the cube
+    Off,
+    /// The letter of the face turns it clockwise. With shift it turns anticlockwise.
+    #[default]
+    Letters,
+    /// The layout used by most virtual cube timers, with both hands on the home row
+    Speedcube,
+}
+
+impl KeyMap {
+    pub const ALL: [KeyMap; 3] = [KeyMap::Off, KeyMap::Letters, KeyMap::Speedcube];
+
+    const SPEEDCUBE_KEYS: [(char, Move); 12] = [
+        ('j', Move::U1),
+        ('f', Move::U3),
+        ('i', Move::R1),
+        ('k', Move::R3),
+        ('h', Move::F1),
+        ('g', Move::F3),
+        ('s', Move::D1),
+        ('l', Move::D3),
+        ('d', Move::L1),
+        ('e', Move::L3),
+        ('w', Move::B1),
+        ('o', Move::B3),
+    ];
+
+    pub fn get_name(self) -> &'static str {
+        match self {
+            KeyMap::Off => "Off",
+            KeyMap::Letters => "Letters",
+            KeyMap::Speedcube => "Speedcube",
+        }
+    }
+
+    /// The key map after this one, for cycling through them
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|k| *k == self)
+            .unwrap_or_default();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// The move made by pressing the key. Keys are the `key` values of keyboard events.
+    pub fn get_move(self, key: &str, shift: bool) -> Option<Move> {
+        let mut chars = key.chars();
+        let c = chars.next()?.to_ascii_lowercase();
+        if chars.next().is_some() {
+            return None;
+        }
+
+        match self {
+            KeyMap::Off => None,
+            KeyMap::Letters => {
+                let face = FaceColor::from_notation_char(c.to_ascii_uppercase())?;
+                let number = if shift {
+                    MoveNumber::Three
+                } else {
+                    MoveNumber::One
+                };
+                Some(Move::from((face, number)))
+            }
+            KeyMap::Speedcube => Self::SPEEDCUBE_KEYS
+                .iter()
+                .find(|(k, _)| *k == c)
+                .map(|(_, m)| *m),
+        }
+    }
+
+    /// A description of the key which makes the move, if there is one
+    pub fn get_key_description(self, m: Move) -> Option<String> {
+        match self {
+            KeyMap::Off => None,
+            KeyMap::Letters => {
+                let c = m.get_color().get_notation_char();
+                match m.into_move_number() {
+                    MoveNumber::One => Some(c.to_string()),
+                    MoveNumber::Two => None,
+                    MoveNumber::Three => Some(format!("Shift+{}", c)),
+                }
+            }
+            KeyMap::Speedcube => Self::SPEEDCUBE_KEYS
+                .iter()
+                .find(|(_, km)| *km == m)
+                .map(|(k, _)| k.to_ascii_uppercase().to_string()),
+        }
+    }
+}
@@ -0,0 +1,373 @@
+use crate::core::prelude::*;
+
+use itertools::Itertools;
+use log::debug;
+use strum::EnumCount;
+
+/// What is required of the piece in a particular position
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Copy, Clone, Default)]
+pub enum MaskRequirement {
+    /// This position does not matter
+    #[default]
+    Ignore,
+    /// Whatever piece is in this position must be correctly oriented
+    Orientation,
+    /// The piece which belongs in this position must be there, correctly oriented
+    Solved,
+}
+
+/// The pieces which must be solved, by position
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone, Default)]
+pub struct PieceMask {
+    pub edges: [MaskRequirement; 12],
+    pub corners: [MaskRequirement; 8],
+}
+
+impl PieceMask {
+    pub fn with_edges(mut self, edges: &[EdgePosition], requirement: MaskRequirement) -> Self {
+        for &e in edges {
+            self.edges[e as usize] = requirement;
+        }
+        self
+    }
+
+    pub fn with_corners(
+        mut self,
+        corners: &[CornerPosition],
+        requirement: MaskRequirement,
+    ) -> Self {
+        for &c in corners {
+            self.corners[c as usize] = requirement;
+        }
+        self
+    }
+
+    /// The cross on the down face
+    pub fn cross() -> Self {
+        Self::default().with_edges(&EdgePosition::DEFAULT_DOWN_EDGES, MaskRequirement::Solved)
+    }
+
+    /// The cross on the down face plus one first two layers pair
+    pub fn cross_and_pair(corner: CornerPosition, edge: EdgePosition) -> Self {
+        Self::cross()
+            .with_corners(&[corner], MaskRequirement::Solved)
+            .with_edges(&[edge], MaskRequirement::Solved)
+    }
+
+    /// The first two layers solved and the up face oriented
+    pub fn oriented_last_layer() -> Self {
+        Self::default()
+            .with_edges(&EdgePosition::DEFAULT_DOWN_EDGES, MaskRequirement::Solved)
+            .with_edges(&EdgePosition::DEFAULT_SLICE_EDGES, MaskRequirement::Solved)
+            .with_corners(
+                &CornerPosition::DEFAULT_ARRAY[4..8],
+                MaskRequirement::Solved,
+            )
+            .with_edges(
+                &EdgePosition::DEFAULT_UP_EDGES,
+                MaskRequirement::Orientation,
+            )
+            .with_corners(
+                &CornerPosition::DEFAULT_ARRAY[0..4],
+                MaskRequirement::Orientation,
+            )
+    }
+
+    /// Does this cube meet all of the requirements
+    pub fn matches(&self, cube: &CubieCube) -> bool {
+        let edges_match = self.edges.iter().enumerate().all(|(i, r)| match r {
+            MaskRequirement::Ignore => true,
+            MaskRequirement::Orientation => cube.edge_orientations[i] == EdgeOrientation::Zero,
+            MaskRequirement::Solved => {
+                cube.edge_orientations[i] == EdgeOrientation::Zero
+                    && cube.edge_positions[i] as usize == i
+            }
+        });
+
+        let corners_match = self.corners.iter().enumerate().all(|(i, r)| match r {
+            MaskRequirement::Ignore => true,
+            MaskRequirement::Orientation => cube.corner_orientations[i] == CornerOrientation::Zero,
+            MaskRequirement::Solved => {
+                cube.corner_orientations[i] == CornerOrientation::Zero
+                    && cube.corner_positions[i] as usize == i
+            }
+        });
+
+        edges_match && corners_match
+    }
+}
+
+/// A piece whose position and orientation is tracked by a pruning table
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum TrackedPiece {
+    Edge(EdgePosition),
+    Corner(CornerPosition),
+}
+
+impl TrackedPiece {
+    /// Each piece has 24 states - 12 positions * 2 orientations for edges, 8 positions * 3 orientations for corners
+    const NSTATES: usize = 24;
+
+    fn get_state(self, cube: &CubieCube) -> usize {
+        match self {
+            TrackedPiece::Edge(edge) => {
+                let position = cube.edge_positions.iter().position(|&e| e == edge).unwrap();
+                (position * 2) + cube.edge_orientations[position] as usize
+            }
+            TrackedPiece::Corner(corner) => {
+                let position = cube
+                    .corner_positions
+                    .iter()
+                    .position(|&c| c == corner)
+                    .unwrap();
+                (position * 3) + cube.corner_orientations[position] as usize
+            }
+        }
+    }
+
+    fn get_state_after_move(self, state: usize, m: Move) -> usize {
+        let move_cube = m.get_cube();
+        match self {
+            TrackedPiece::Edge(_) => {
+                let (position, orientation) = (state / 2, state % 2);
+                let new_position = move_cube
+                    .edge_positions
+                    .iter()
+                    .position(|&e| e as usize == position)
+                    .unwrap();
+                let new_orientation =
+                    (orientation + move_cube.edge_orientations[new_position] as usize) % 2;
+                (new_position * 2) + new_orientation
+            }
+            TrackedPiece::Corner(_) => {
+                let (position, orientation) = (state / 3, state % 3);
+                let new_position = move_cube
+                    .corner_positions
+                    .iter()
+                    .position(|&c| c as usize == position)
+                    .unwrap();
+                let new_orientation =
+                    (orientation + move_cube.corner_orientations[new_position] as usize) % 3;
+                (new_position * 3) + new_orientation
+            }
+        }
+    }
+
+    fn solved_state(self) -> usize {
+        match self {
+            TrackedPiece::Edge(edge) => edge as usize * 2,
+            TrackedPiece::Corner(corner) => corner as usize * 3,
+        }
+    }
+}
+
+/// Finds the shortest sequence of moves which solves the pieces in a mask, using IDA*.
+/// The pruning tables track groups of up to four solved pieces, plus the orientation of the masked positions.
+pub struct MaskedSolver {
+    mask: PieceMask,
+    /// Groups of pieces and the depth of each combination of their states
+    piece_groups: Vec<(Vec<TrackedPiece>, Vec<u8>)>,
+    flip_depth: Vec<u8>,
+    twist_depth: Vec<u8>,
+}
+
+impl MaskedSolver {
+    const GROUP_SIZE: usize = 4;
+
+    pub fn new(mask: PieceMask) -> Self {
+        let tracked = EdgePosition::DEFAULT_ARRAY
+            .into_iter()
+            .filter(|&e| mask.edges[e as usize] == MaskRequirement::Solved)
+            .map(TrackedPiece::Edge)
+            .chain(
+                CornerPosition::DEFAULT_ARRAY
+                    .into_iter()
+                    .filter(|&c| mask.corners[c as usize] == MaskRequirement::Solved)
+                    .map(TrackedPiece::Corner),
+            )
+            .collect_vec();
+
+        let piece_groups = tracked
+            .chunks(Self::GROUP_SIZE)
+            .map(|group| (group.to_vec(), Self::create_group_depth(group)))
+            .collect_vec();
+
+        let flip_move = FlipProperty::create(&FlipProperty {});
+        let flip_depth = Self::create_orientation_depth(NFLIP, &flip_move, |flip| {
+            let mut cube = CubieCube::default();
+            cube.set_flip(flip);
+            (0..EdgePosition::COUNT).all(|i| {
+                mask.edges[i] == MaskRequirement::Ignore
+                    || cube.edge_orientations[i] == EdgeOrientation::Zero
+            })
+        });
+
+        let twist_move = TwistProperty::create(&TwistProperty {});
+        let twist_depth = Self::create_orientation_depth(NTWIST, &twist_move, |twist| {
+            let mut cube = CubieCube::default();
+            cube.set_twist(twist);
+            (0..CornerPosition::COUNT).all(|i| {
+                mask.corners[i] == MaskRequirement::Ignore
+                    || cube.corner_orientations[i] == CornerOrientation::Zero
+            })
+        });
+
+        Self {
+            mask,
+            piece_groups,
+            flip_depth,
+            twist_depth,
+        }
+    }
+
+    fn get_group_index(states: impl Iterator<Item = usize>) -> usize {
+        states.fold(0, |index, state| (index * TrackedPiece::NSTATES) + state)
+    }
+
+    /// Breadth first search from the solved state of a group of pieces
+    fn create_group_depth(group: &[TrackedPiece]) -> Vec<u8> {
+        let size = TrackedPiece::NSTATES.pow(group.len() as u32);
+        let mut table = vec![u8::MAX; size];
+
+        let solved = Self::get_group_index(group.iter().map(|p| p.solved_state()));
+        table[solved] = 0;
+
+        let mut depth = 0;
+        let mut next = vec![solved];
+        let mut current: Vec<usize> = Vec::new();
+
+        while !next.is_empty() {
+            std::mem::swap(&mut next, &mut current);
+
+            for index in current.drain(..) {
+                let states = Self::split_group_index(group, index);
+
+                for m in Move::ALLMOVES {
+                    let index_after_move = Self::get_group_index(
+                        states
+                            .iter()
+                            .zip(group)
+                            .map(|(&s, piece)| piece.get_state_after_move(s, m)),
+                    );
+
+                    if table[index_after_move] == u8::MAX {
+                        table[index_after_move] = depth + 1;
+                        next.push(index_after_move);
+                    }
+                }
+            }
+            depth += 1;
+        }
+
+        table
+    }
+
+    fn split_group_index(group: &[TrackedPiece], mut index: usize) -> Vec<usize> {
+        let mut states = vec![0; group.len()];
+        for state in states.iter_mut().rev() {
+            *state = index % TrackedPiece::NSTATES;
+            index /= TrackedPiece::NSTATES;
+        }
+        states
+    }
+
+    /// Breadth first search from every orientation which meets the mask
+    fn create_orientation_depth(
+        size: usize,
+        move_table: &[u16],
+        is_goal: impl Fn(u16) -> bool,
+    ) -> Vec<u8> {
+        let mut table = vec![u8::MAX; size];
+
+        let mut next = (0..size as u16).filter(|&v| is_goal(v)).collect_vec();
+        for &v in next.iter() {
+            table[v as usize] = 0;
+        }
+
+        let mut depth = 0;
+        let mut current: Vec<u16> = Vec::new();
+
+        while !next.is_empty() {
+            std::mem::swap(&mut next, &mut current);
+
+            for value in current.drain(..) {
+                for m in Move::ALLMOVES {
+                    let value_after_move =
+                        move_table[(value as usize * Move::ALLMOVES.len()) + m as usize];
+                    if table[value_after_move as usize] == u8::MAX {
+                        table[value_after_move as usize] = depth + 1;
+                        next.push(value_after_move);
+                    }
+                }
+            }
+            depth += 1;
+        }
+
+        table
+    }
+
+    fn get_lower_bound(&self, cube: &CubieCube) -> u8 {
+        let group_depth = self
+            .piece_groups
+            .iter()
+            .map(|(group, table)| {
+                table[Self::get_group_index(group.iter().map(|p| p.get_state(cube)))]
+            })
+            .max()
+            .unwrap_or_default();
+
+        group_depth
+            .max(self.flip_depth[cube.get_flip() as usize])
+            .max(self.twist_depth[cube.get_twist() as usize])
+    }
+
+    /// Finds a shortest sequence of moves after which the cube matches the mask, with at most max_length moves
+    pub fn solve(&self, cube: &CubieCube, max_length: u8) -> Option<Vec<Move>> {
+        let mut bound = self.get_lower_bound(cube);
+        let mut moves: Vec<Move> = Vec::new();
+
+        while bound <= max_length {
+            debug!("Searching for masked solution with {} moves", bound);
+            if self.search(cube, 0, bound, &mut moves) {
+                return Some(moves);
+            }
+            bound += 1;
+        }
+
+        None
+    }
+
+    fn search(&self, cube: &CubieCube, depth: u8, bound: u8, moves: &mut Vec<Move>) -> bool {
+        if self.mask.matches(cube) {
+            return true;
+        }
+        if depth == bound {
+            return false;
+        }
+
+        for m in Move::ALLMOVES {
+            if let Some(&previous) = moves.last() {
+                if !previous.can_precede(m) {
+                    continue;
+                }
+                //Moves on opposite faces commute so only search them in one order
+                if previous.get_color() == m.get_opposite_color()
+                    && previous.get_color() > m.get_color()
+                {
+                    continue;
+                }
+            }
+
+            let next = m.apply(cube);
+            if depth + 1 + self.get_lower_bound(&next) <= bound {
+                moves.push(m);
+                if self.search(&next, depth + 1, bound, moves) {
+                    return true;
+                }
+                moves.pop();
+            }
+        }
+
+        false
+    }
+}
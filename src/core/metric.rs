@@ -0,0 +1,97 @@
+use crate::core::prelude::*;
+
+use strum_macros::*;
+
+/// The way that the length of a solution is measured
+#[derive(
+    Debug,
+    Display,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    Copy,
+    Clone,
+    Default,
+    EnumIter,
+    Hash,
+)]
+pub enum Metric {
+    /// Half Turn Metric (HTM) - every face turn costs one
+    #[default]
+    HalfTurn,
+    /// Quarter Turn Metric (QTM) - half turns cost two
+    QuarterTurn,
+    /// Slice Turn Metric (STM) - a pair of opposite face turns which make a slice move only costs one.
+    /// Only consecutive turns of opposite faces in opposite directions, such as `R L'` or `U2 D2`, count as a slice move.
+    /// `R L` costs two as it is a rotation of the middle layer relative to both faces.
+    SliceTurn,
+}
+
+impl Metric {
+    /// The cost of doing a move immediately after the previous move
+    pub const fn get_cost(self, m: Move, previous: Option<Move>) -> u8 {
+        match self {
+            Metric::HalfTurn => 1,
+            Metric::QuarterTurn => match m.into_move_number() {
+                MoveNumber::Two => 2,
+                _ => 1,
+            },
+            Metric::SliceTurn => match previous {
+                Some(p) if Self::is_slice_pair(p, m) => 0,
+                _ => 1,
+            },
+        }
+    }
+
+    /// Whether two consecutive moves on opposite faces together make a slice move e.g. R L' or U2 D2
+    const fn is_slice_pair(m1: Move, m2: Move) -> bool {
+        (m1.get_color() as u8 + 3) % 6 == m2.get_color() as u8
+            && (m1.into_move_number() as u8 + m2.into_move_number() as u8) == 4
+    }
+
+    /// The total cost of a sequence of moves
+    pub fn count(self, moves: &[Move]) -> usize {
+        let mut previous: Option<Move> = None;
+        let mut total = 0;
+
+        for &m in moves {
+            //A move can only be part of one slice pair
+            let cost = self.get_cost(m, previous);
+            total += cost as usize;
+            previous = if cost == 0 { None } else { Some(m) };
+        }
+
+        total
+    }
+
+    /// Converts a lower bound on the number of face turns into a lower bound in this metric
+    pub const fn lower_bound(self, half_turn_depth: u8) -> u8 {
+        match self {
+            Metric::HalfTurn => half_turn_depth,
+            Metric::QuarterTurn => half_turn_depth,
+            Metric::SliceTurn => half_turn_depth.div_ceil(2),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ntest::test_case;
+
+    #[test_case("R U2 F'", 3, 4, 3)]
+    #[test_case("R L' U2 D2", 4, 6, 2)]
+    #[test_case("R L U D'", 4, 4, 3)]
+    #[test_case("R L' R", 3, 3, 2)]
+    fn test_count(text: &str, htm: usize, qtm: usize, stm: usize) {
+        let moves = Move::parse_sequence(text).unwrap();
+
+        assert_eq!(Metric::HalfTurn.count(&moves), htm);
+        assert_eq!(Metric::QuarterTurn.count(&moves), qtm);
+        assert_eq!(Metric::SliceTurn.count(&moves), stm);
+    }
+}
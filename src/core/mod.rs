@@ -1,39 +1,70 @@
+mod algorithm;
 mod basic_cubes;
 mod coordinate_cube;
 mod corner_position;
+mod corner_pruning;
+mod cube_error;
 mod cube_invariant_getters;
 mod cube_invariant_setters;
 mod cube_property;
 mod cubie_cube;
 mod data_source;
 mod data_source_creator;
+mod data_source_io;
 mod definitions;
 mod edge_position;
+mod extended_moves;
 mod facelet_cube;
 mod facelet_position;
+mod generation_progress;
+mod key_map;
+mod masked_solver;
+mod metric;
 mod misc;
 mod moves;
+#[cfg(not(target_arch = "wasm32"))]
+mod parallel_solver;
 mod phase_one_pruning;
 mod phase_two_pruning;
+mod restricted_solver;
+mod scrambler;
+mod short_scramble_optimal_solver;
+mod share_code;
+mod solve_report;
 mod solver;
 
 pub mod prelude {
+    pub use crate::core::algorithm::*;
     pub use crate::core::basic_cubes::*;
     pub use crate::core::coordinate_cube::*;
     pub use crate::core::corner_position::*;
+    pub use crate::core::cube_error::*;
     pub use crate::core::cube_invariant_getters::*;
     pub use crate::core::cube_invariant_setters::*;
     pub use crate::core::cube_property::*;
     pub use crate::core::cubie_cube::*;
     pub use crate::core::data_source::*;
     pub use crate::core::data_source_creator::*;
+    pub use crate::core::data_source_io::*;
     pub use crate::core::definitions::*;
     pub use crate::core::edge_position::*;
+    pub use crate::core::extended_moves::*;
     pub use crate::core::facelet_cube::*;
     pub use crate::core::facelet_position::*;
+    pub use crate::core::generation_progress::*;
+    pub use crate::core::key_map::*;
+    pub use crate::core::masked_solver::*;
+    pub use crate::core::metric::*;
     pub use crate::core::misc::*;
     pub use crate::core::moves::*;
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use crate::core::parallel_solver::*;
     pub use crate::core::phase_one_pruning::*;
     pub use crate::core::phase_two_pruning::*;
+    pub use crate::core::restricted_solver::*;
+    pub use crate::core::scrambler::*;
+    pub use crate::core::short_scramble_optimal_solver::*;
+    pub use crate::core::share_code::*;
+    pub use crate::core::solve_report::*;
     pub use crate::core::solver::*;
 }
@@ -2,6 +2,7 @@ use crate::core::prelude::FaceColor::*;
 
 use crate::core::prelude::*;
 
+use itertools::Itertools;
 use strum_macros::*;
 
 #[derive(
@@ -18,6 +19,7 @@ use strum_macros::*;
     FromRepr,
     EnumIter,
     EnumCount,
+    Hash,
 )]
 #[repr(u8)]
 pub enum Move {
@@ -172,3 +174,208 @@ impl Move {
         cube.corner_multiply(self.get_cube())
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MoveParseError {
+    ///The token does not start with a recognised face letter such as U R F D L B
+    UnknownFace { token: String, column: usize },
+    ///The face letter is followed by something other than 1, 2, 3, ' or 2'
+    BadSuffix { token: String, column: usize },
+}
+
+impl MoveParseError {
+    pub fn token(&self) -> &str {
+        match self {
+            MoveParseError::UnknownFace { token, column: _ } => token,
+            MoveParseError::BadSuffix { token, column: _ } => token,
+        }
+    }
+
+    /// The 1-based column of the start of the offending token
+    pub fn column(&self) -> usize {
+        match self {
+            MoveParseError::UnknownFace { token: _, column } => *column,
+            MoveParseError::BadSuffix { token: _, column } => *column,
+        }
+    }
+}
+
+impl std::fmt::Display for MoveParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoveParseError::UnknownFace { token, column } => {
+                write!(f, "Unknown face in '{}' at column {}", token, column)
+            }
+            MoveParseError::BadSuffix { token, column } => {
+                write!(f, "Bad move suffix in '{}' at column {}", token, column)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MoveParseError {}
+
+impl FaceColor {
+    ///The letter used for this face in Singmaster notation
+    pub const fn get_notation_char(self) -> char {
+        match self {
+            Up => 'U',
+            Right => 'R',
+            Front => 'F',
+            Down => 'D',
+            Left => 'L',
+            Back => 'B',
+        }
+    }
+
+    pub const fn from_notation_char(c: char) -> Option<Self> {
+        match c {
+            'U' => Some(Up),
+            'R' => Some(Right),
+            'F' => Some(Front),
+            'D' => Some(Down),
+            'L' => Some(Left),
+            'B' => Some(Back),
+            _ => None,
+        }
+    }
+}
+
+impl MoveNumber {
+    ///The suffix used for this number of turns in Singmaster notation
+    pub const fn get_notation_suffix(self) -> &'static str {
+        match self {
+            MoveNumber::One => "",
+            MoveNumber::Two => "2",
+            MoveNumber::Three => "'",
+        }
+    }
+
+    ///Parses a Singmaster move suffix. The numeric forms 1 and 3 are also accepted.
+    pub fn from_notation_suffix(suffix: &str) -> Option<Self> {
+        match suffix {
+            "" | "1" => Some(MoveNumber::One),
+            "2" | "2'" | "2’" => Some(MoveNumber::Two),
+            "'" | "’" | "3" => Some(MoveNumber::Three),
+            _ => None,
+        }
+    }
+}
+
+impl Move {
+    /// Gets the Singmaster notation for this move e.g. R, R2 or R'
+    pub fn get_notation(self) -> String {
+        format!(
+            "{}{}",
+            self.get_color().get_notation_char(),
+            self.into_move_number().get_notation_suffix()
+        )
+    }
+
+    /// Formats a sequence of moves in Singmaster notation, separated by spaces
+    pub fn format_sequence(moves: &[Move]) -> String {
+        moves.iter().map(|m| m.get_notation()).join(" ")
+    }
+
+    /// Parses a whitespace separated sequence of moves such as "R U R' U' F2"
+    /// The numeric form used by `Display` (e.g. "R3") is also accepted
+    pub fn parse_sequence(text: &str) -> Result<Vec<Move>, MoveParseError> {
+        parse_tokens(text, Self::parse_token)
+    }
+
+    fn parse_token(token: &str, column: usize) -> Result<Move, MoveParseError> {
+        let mut chars = token.chars();
+
+        let color = chars
+            .next()
+            .and_then(FaceColor::from_notation_char)
+            .ok_or_else(|| MoveParseError::UnknownFace {
+                token: token.to_string(),
+                column,
+            })?;
+
+        let number = MoveNumber::from_notation_suffix(chars.as_str()).ok_or_else(|| {
+            MoveParseError::BadSuffix {
+                token: token.to_string(),
+                column,
+            }
+        })?;
+
+        Ok(Move::from((color, number)))
+    }
+}
+
+/// Splits text on whitespace and parses each token.
+/// The token parser is given the token and its 1-based column
+pub(crate) fn parse_tokens<T>(
+    text: &str,
+    parse_token: impl Fn(&str, usize) -> Result<T, MoveParseError>,
+) -> Result<Vec<T>, MoveParseError> {
+    let mut results = Vec::new();
+    let mut token_start: Option<usize> = None;
+
+    for (column, c) in text
+        .chars()
+        .enumerate()
+        .chain(std::iter::once((usize::MAX, ' ')))
+    {
+        if c.is_whitespace() {
+            if let Some(start) = token_start.take() {
+                let token: String = text.chars().skip(start).take(column - start).collect();
+                results.push(parse_token(&token, start + 1)?);
+            }
+        } else if token_start.is_none() {
+            token_start = Some(column);
+        }
+    }
+
+    Ok(results)
+}
+
+impl std::str::FromStr for Move {
+    type Err = MoveParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_token(s.trim(), 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ntest::test_case;
+
+    #[test_case("R U R' U'", "R U R' U'")]
+    #[test_case("  F2   B1\tD3 ", "F2 B D'")]
+    #[test_case("U2' L’", "U2 L'")]
+    #[test_case("", "")]
+    fn test_parse_and_format(text: &str, expected: &str) {
+        let moves = Move::parse_sequence(text).unwrap();
+        assert_eq!(Move::format_sequence(&moves), expected);
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let text = Move::format_sequence(&Move::ALLMOVES);
+        assert_eq!(Move::parse_sequence(&text).unwrap(), Move::ALLMOVES.to_vec());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Move::parse_sequence("R U X2"),
+            Err(MoveParseError::UnknownFace {
+                token: "X2".to_string(),
+                column: 5
+            })
+        );
+        assert_eq!(
+            Move::parse_sequence("R U2x F"),
+            Err(MoveParseError::BadSuffix {
+                token: "U2x".to_string(),
+                column: 3
+            })
+        );
+    }
+}
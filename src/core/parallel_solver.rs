@@ -0,0 +1,141 @@
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+
+use crate::core::prelude::*;
+
+use itertools::Itertools;
+
+/// Solves cubes on several threads. Only available on native targets.
+pub struct ParallelSolver {}
+
+impl ParallelSolver {
+    /// Searches from each of the six starting states on its own thread.
+    /// The threads share the cost of the best solution so far, so every thread can prune with it.
+    /// The `max_iterations` are split evenly between the threads, so the search does about as much work as a serial one.
+    /// Each thread has its own record of the cubes it has seen.
+    pub fn solve(
+        base_cube: CubieCube,
+        data_source: Arc<DataSource>,
+        settings: SolveSettings,
+    ) -> SolveOutcome {
+        let start = Instant::now();
+        let shared_best = Arc::new(AtomicU8::new(u8::MAX));
+        let starts = Solver::create_start_states(&base_cube, &data_source);
+        let thread_settings = SolveSettings {
+            max_iterations: settings.max_iterations.div_ceil(starts.len()),
+            ..settings.clone()
+        };
+
+        let results = thread::scope(|scope| {
+            let handles = starts
+                .into_iter()
+                .map(|state| {
+                    let data_source = data_source.clone();
+                    let shared_best = shared_best.clone();
+                    let settings = &thread_settings;
+
+                    scope.spawn(move || {
+                        let mut coordinator = SerialSolveCoordinator::new(
+                            data_source,
+                            vec![state],
+                            settings,
+                            SolutionLimit::Best,
+                        );
+                        coordinator.shared_best = Some(shared_best);
+
+                        let stop_reason = coordinator.solve(settings);
+                        (coordinator.solution, stop_reason, coordinator.report)
+                    })
+                })
+                .collect_vec();
+
+            handles
+                .into_iter()
+                .map(|h| h.join().expect("Solver thread panicked"))
+                .collect_vec()
+        });
+
+        let best = results
+            .iter()
+            .filter_map(|(solution, _, _)| solution.as_ref())
+            .min_by_key(|s| s.get_cost());
+
+        let stop_reason = Self::combine_stop_reasons(results.iter().map(|(_, r, _)| *r));
+        let solution = best.map(|s| Algorithm::from(s.get_moves()).simplify().into());
+        let winning_start = best.map(|s| s.get_start());
+
+        let mut report = SolveReport::merge(results.into_iter().map(|(_, _, report)| report));
+        report.winning_start = winning_start;
+        report.elapsed = start.elapsed();
+
+        SolveOutcome {
+            solution,
+            stop_reason,
+            report,
+        }
+    }
+
+    /// Solves many cubes with the serial solver, spreading them across the available threads.
+    /// The settings apply to each cube separately, so each cube may use the whole of `max_iterations` on its thread.
+    /// The outcomes are in the same order as the cubes.
+    pub fn solve_batch(
+        cubes: &[CubieCube],
+        data_source: Arc<DataSource>,
+        settings: SolveSettings,
+    ) -> Vec<SolveOutcome> {
+        let threads = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+            .min(cubes.len());
+        let next_index = AtomicUsize::new(0);
+
+        let mut outcomes = thread::scope(|scope| {
+            let handles = (0..threads)
+                .map(|_| {
+                    let data_source = data_source.clone();
+                    let settings = &settings;
+                    let next_index = &next_index;
+
+                    scope.spawn(move || {
+                        let mut outcomes: Vec<(usize, SolveOutcome)> = Vec::new();
+                        loop {
+                            let index = next_index.fetch_add(1, Ordering::Relaxed);
+                            let Some(cube) = cubes.get(index) else {
+                                return outcomes;
+                            };
+                            let outcome =
+                                Solver::solve(cube.clone(), data_source.clone(), settings.clone());
+                            outcomes.push((index, outcome));
+                        }
+                    })
+                })
+                .collect_vec();
+
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("Solver thread panicked"))
+                .collect_vec()
+        });
+
+        outcomes.sort_by_key(|(index, _)| *index);
+        outcomes
+            .into_iter()
+            .map(|(_, outcome)| outcome)
+            .collect_vec()
+    }
+
+    /// The reason the whole search stopped, given why each thread stopped
+    fn combine_stop_reasons(reasons: impl Iterator<Item = StopReason>) -> StopReason {
+        reasons
+            .max_by_key(|reason| match reason {
+                StopReason::Exhausted => 0,
+                StopReason::IterationLimit => 1,
+                StopReason::TimedOut => 2,
+                StopReason::Cancelled => 3,
+                StopReason::FoundTarget => 4,
+            })
+            .unwrap_or(StopReason::Exhausted)
+    }
+}
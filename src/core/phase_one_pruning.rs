@@ -1,5 +1,8 @@
 use crate::core::prelude::*;
 
+/// The number of states filled in by the quick phase 1 pruning table, which stops after the first round
+const QUICK_PHASE_1_PRUNING_STATES: usize = 8802059;
+
 impl DataSource {
     fn set_flip_slice_twist_depth3(ix: usize, value: u32, tab: &mut [u32]) {
         let shift = (ix % 16) * 2;
@@ -21,10 +24,12 @@ impl DataSource {
         quick: bool,
         moves_source: &MovesSource,
         flip_slice_source: &FlipSliceSource,
-    ) -> Vec<u32> {
-        let total = NFLIPSLICECLASS * NTWIST;
+        observer: &mut impl GenerationObserver,
+    ) -> Result<Vec<u32>, GenerationCancelled> {
+        let size = NFLIPSLICECLASS * NTWIST;
+        let total = if quick { QUICK_PHASE_1_PRUNING_STATES } else { size };
         let fs_sym = DataSource::make_flip_slice_sym(flip_slice_source);
-        let mut table = vec![u32::MAX; (total / 16) + 1];
+        let mut table = vec![u32::MAX; (size / 16) + 1];
 
         let _twist = 0;
         Self::set_flip_slice_twist_depth3(0, 0, &mut table);
@@ -108,10 +113,20 @@ impl DataSource {
                 }
             }
             depth += 1;
+
+            report_progress(
+                observer,
+                GenerationProgress {
+                    table: GenerationTable::Phase1Pruning,
+                    depth,
+                    done,
+                    total,
+                },
+            )?;
         }
 
         if quick{
-            return table;
+            return Ok(table);
         }
 
         while done < total {
@@ -119,6 +134,19 @@ impl DataSource {
 
             let mut idx = 0;
             for fs_class_index in 0..NFLIPSLICECLASS {
+                //each depth takes a long time here so report part way through
+                if fs_class_index % 4096 == 0 {
+                    report_progress(
+                        observer,
+                        GenerationProgress {
+                            table: GenerationTable::Phase1Pruning,
+                            depth,
+                            done,
+                            total,
+                        },
+                    )?;
+                }
+
                 let mut twist = 0;
                 while twist < 2187 {
                     let is_match = Self::get_flip_slice_twist_depth3(idx, &table) == 3;
@@ -160,7 +188,17 @@ impl DataSource {
             depth += 1;
         }
 
-        table
+        report_progress(
+            observer,
+            GenerationProgress {
+                table: GenerationTable::Phase1Pruning,
+                depth,
+                done,
+                total,
+            },
+        )?;
+
+        Ok(table)
     }
 
     pub fn make_flip_slice_sym(flip_slice_source: &FlipSliceSource) -> [u16; NFLIPSLICECLASS] {
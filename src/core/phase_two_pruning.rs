@@ -1,5 +1,8 @@
 use crate::core::prelude::*;
 
+/// The number of states filled in by the ten depths of the phase 2 pruning table
+const PHASE_2_PRUNING_STATES: usize = 2473211;
+
 impl DataSource {
     fn set_corners_ud_edges_depth3(ix: usize, value: u32, tab: &mut [u32]) {
         let shift = (ix % 16) * 2;
@@ -17,7 +20,9 @@ impl DataSource {
         y & 3
     }
 
-    pub fn create_phase_2_edge_merge() -> Vec<u16> {
+    pub fn create_phase_2_edge_merge(
+        observer: &mut impl GenerationObserver,
+    ) -> Result<Vec<u16>, GenerationCancelled> {
         let mut table = vec![u16::MIN; 24 * 1680];
 
         let mut cu = CubieCube::default();
@@ -25,6 +30,12 @@ impl DataSource {
         let mut cud_edge_positions = CubieCube::default().edge_positions;
 
         for i in 0..1680 {
+            if i % 120 == 0 {
+                report_progress(
+                    observer,
+                    GenerationProgress::step(GenerationTable::Phase2EdgeMerge, i.into(), 1680),
+                )?;
+            }
             cu.set_u_edges(i);
             for j in 0..70 {
                 cd.set_d_edges(j * 24);
@@ -67,18 +78,26 @@ impl DataSource {
             }
         }
 
-        table
+        report_progress(
+            observer,
+            GenerationProgress::step(GenerationTable::Phase2EdgeMerge, 1680, 1680),
+        )?;
+
+        Ok(table)
     }
 
     pub fn create_phase_2_pruning(
         moves_source: &MovesSource,
         corners_source: &CornerSymmetriesSource,
-    ) -> Vec<u32> {
+        observer: &mut impl GenerationObserver,
+    ) -> Result<Vec<u32>, GenerationCancelled> {
+        let total = PHASE_2_PRUNING_STATES;
         let c_sym = DataSource::make_corners_sym(corners_source);
-        let mut table = vec![u32::MAX; 40320 * 2768 / 16];
+        let mut table = vec![u32::MAX; (40320 * 2768) / 16];
 
         let _ud_edge = 0;
         Self::set_corners_ud_edges_depth3(0, 0, &mut table);
+        let mut done = 1;
         let mut depth = 0u32;
 
         let mut next = vec![(0, 0)]; //next is tuples of (corner_class_index, ud_edges_conj)
@@ -107,6 +126,7 @@ impl DataSource {
                     if Self::get_corners_ud_edges_depth3(idx1, &table) == 3 {
                         //this index has not yet been set
                         Self::set_corners_ud_edges_depth3(idx1, (depth + 1) % 3, &mut table);
+                        done += 1;
                         next.push((corner_class_index_after_move, udedge1_conj));
 
                         let mut sym = c_sym[corner_class_index_after_move as usize];
@@ -127,6 +147,7 @@ impl DataSource {
                                             (depth + 1) % 3,
                                             &mut table,
                                         );
+                                        done += 1;
                                         next.push((
                                             corner_class_index_after_move,
                                             ud_edges_after_move_and_symmetry,
@@ -139,12 +160,22 @@ impl DataSource {
                 }
             }
             depth += 1;
+
+            report_progress(
+                observer,
+                GenerationProgress {
+                    table: GenerationTable::Phase2Pruning,
+                    depth,
+                    done,
+                    total,
+                },
+            )?;
         }
 
-        table
+        Ok(table)
     }
 
-    fn make_corners_sym(corners_source: &CornerSymmetriesSource) -> [u16; 2768] {
+    pub fn make_corners_sym(corners_source: &CornerSymmetriesSource) -> [u16; 2768] {
         let mut cc = CubieCube::default();
         let mut c_sym = [u16::MIN; 2768];
         for (i, item) in c_sym.iter_mut().enumerate() {
@@ -0,0 +1,371 @@
+use std::rc::Rc;
+
+use crate::core::prelude::*;
+
+use itertools::Itertools;
+use log::debug;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RestrictedSolveError {
+    /// The cube can not be solved using only the allowed moves
+    NotReachable,
+    /// No solution was found using at most this many moves
+    NotFoundWithinLength(u8),
+    /// Only face turns can be generators. Slice, wide and rotation moves turn the centres, which the solver does not track.
+    UnsupportedMove(ExtendedMove),
+}
+
+impl std::fmt::Display for RestrictedSolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RestrictedSolveError::NotReachable => {
+                write!(f, "The cube is not reachable using only these moves")
+            }
+            RestrictedSolveError::NotFoundWithinLength(length) => {
+                write!(f, "No solution found with {} moves or fewer", length)
+            }
+            RestrictedSolveError::UnsupportedMove(m) => {
+                write!(
+                    f,
+                    "{} turns the centres, so can not be used",
+                    m.get_notation()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for RestrictedSolveError {}
+
+/// Solves cubes using only a subset of the moves e.g. <R,U>, using IDA*.
+/// Whether a cube is in the subgroup at all is tested exactly before searching.
+/// Only face turns can be used. Subgroups such as <M,U>, which move the centres, are rejected by `from_generators`.
+pub struct RestrictedSolver {
+    data_source: Rc<DataSource>,
+    moves: Vec<Move>,
+    group: StickerGroup,
+    corners_depth: Vec<u8>,
+    twist_slice_depth: Vec<u8>,
+    flip_slice_depth: Vec<u8>,
+    u_edges_depth: Vec<u8>,
+    d_edges_depth: Vec<u8>,
+    slice_sorted_depth: Vec<u8>,
+    solved: CoordinateCube,
+}
+
+impl RestrictedSolver {
+    /// Create a solver which may use all turns of the given faces e.g. [Right, Up] for <R,U>
+    pub fn for_faces(data_source: Rc<DataSource>, faces: &[FaceColor]) -> Self {
+        let moves = Move::ALLMOVES
+            .into_iter()
+            .filter(|m| faces.contains(&m.get_color()))
+            .collect_vec();
+
+        Self::new(data_source, &moves)
+    }
+
+    /// Create a solver from generators in extended notation e.g. "R U" for <R,U>.
+    /// Each generator allows every turn of its face.
+    /// Returns an error if any of them is not a face turn, such as M in <M,U>.
+    pub fn from_generators(
+        data_source: Rc<DataSource>,
+        generators: &[ExtendedMove],
+    ) -> Result<Self, RestrictedSolveError> {
+        let faces = generators
+            .iter()
+            .map(|m| match m.kind {
+                ExtendedMoveKind::Face(face) => Ok(face),
+                _ => Err(RestrictedSolveError::UnsupportedMove(*m)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::for_faces(data_source, &faces))
+    }
+
+    pub fn new(data_source: Rc<DataSource>, moves: &[Move]) -> Self {
+        let solved: CoordinateCube = CubieCube::default().into();
+        let ms = &data_source.moves_source;
+
+        let corners_depth =
+            Self::create_depth_table(NCORNERS, solved.corners as usize, moves, |c, m| {
+                ms.get_corners(c as u16, m) as usize
+            });
+        let twist_slice_depth = Self::create_depth_table(NTWIST * NSLICE, 0, moves, |i, m| {
+            let twist = ms.get_twist((i / NSLICE) as u16, m) as usize;
+            let slice = ms.get_slice((i % NSLICE) as u16, m) as usize;
+            (twist * NSLICE) + slice
+        });
+        let flip_slice_depth = Self::create_depth_table(NFLIP * NSLICE, 0, moves, |i, m| {
+            let flip = ms.get_flip((i / NSLICE) as u16, m) as usize;
+            let slice = ms.get_slice((i % NSLICE) as u16, m) as usize;
+            (flip * NSLICE) + slice
+        });
+        let u_edges_depth =
+            Self::create_depth_table(NSLICESORTED, solved.u_edges as usize, moves, |e, m| {
+                ms.get_u_edge(e as u16, m) as usize
+            });
+        let d_edges_depth =
+            Self::create_depth_table(NSLICESORTED, solved.d_edges as usize, moves, |e, m| {
+                ms.get_d_edge(e as u16, m) as usize
+            });
+        let slice_sorted_depth =
+            Self::create_depth_table(NSLICESORTED, solved.slice_sorted as usize, moves, |e, m| {
+                ms.get_slice_sorted(e as u16, m) as usize
+            });
+
+        let group = StickerGroup::new(moves.iter().map(|m| get_stickers(m.get_cube())));
+
+        Self {
+            data_source,
+            moves: moves.to_vec(),
+            group,
+            corners_depth,
+            twist_slice_depth,
+            flip_slice_depth,
+            u_edges_depth,
+            d_edges_depth,
+            slice_sorted_depth,
+            solved,
+        }
+    }
+
+    /// Breadth first search from the solved value using only the allowed moves.
+    /// Values which can not be reached are left as u8::MAX
+    fn create_depth_table(
+        size: usize,
+        solved: usize,
+        moves: &[Move],
+        apply_move: impl Fn(usize, Move) -> usize,
+    ) -> Vec<u8> {
+        let mut table = vec![u8::MAX; size];
+        table[solved] = 0;
+
+        let mut depth = 0;
+        let mut next = vec![solved];
+        let mut current: Vec<usize> = Vec::new();
+
+        while !next.is_empty() {
+            std::mem::swap(&mut next, &mut current);
+
+            for value in current.drain(..) {
+                for &m in moves {
+                    let value_after_move = apply_move(value, m);
+                    if table[value_after_move] == u8::MAX {
+                        table[value_after_move] = depth + 1;
+                        next.push(value_after_move);
+                    }
+                }
+            }
+            depth += 1;
+        }
+
+        table
+    }
+
+    /// Gets a lower bound on the number of moves needed. Returns None if the cube is not reachable.
+    fn get_lower_bound(&self, cube: &CoordinateCube) -> Option<u8> {
+        let slice = (cube.slice_sorted / 24) as usize;
+
+        let depths = [
+            self.corners_depth[cube.corners as usize],
+            self.twist_slice_depth[(cube.twist as usize * NSLICE) + slice],
+            self.flip_slice_depth[(cube.flip as usize * NSLICE) + slice],
+            self.u_edges_depth[cube.u_edges as usize],
+            self.d_edges_depth[cube.d_edges as usize],
+            self.slice_sorted_depth[cube.slice_sorted as usize],
+        ];
+
+        let max = depths.into_iter().max().unwrap();
+        if max == u8::MAX {
+            None
+        } else {
+            Some(max)
+        }
+    }
+
+    /// Finds a shortest solution using only the allowed moves, with at most max_length moves
+    pub fn solve(
+        &self,
+        cube: &CubieCube,
+        max_length: u8,
+    ) -> Result<Vec<Move>, RestrictedSolveError> {
+        if !self.group.contains(&get_stickers(cube)) {
+            return Err(RestrictedSolveError::NotReachable);
+        }
+
+        let root: CoordinateCube = cube.clone().into();
+        let mut bound = self
+            .get_lower_bound(&root)
+            .ok_or(RestrictedSolveError::NotReachable)?;
+        let mut moves: Vec<Move> = Vec::new();
+
+        while bound <= max_length {
+            debug!("Searching for restricted solution with {} moves", bound);
+            if self.search(&root, 0, bound, &mut moves) {
+                return Ok(moves);
+            }
+            bound += 1;
+        }
+
+        Err(RestrictedSolveError::NotFoundWithinLength(max_length))
+    }
+
+    fn search(&self, cube: &CoordinateCube, depth: u8, bound: u8, moves: &mut Vec<Move>) -> bool {
+        if depth == bound {
+            return *cube == self.solved;
+        }
+
+        for &m in self.moves.iter() {
+            if let Some(&previous) = moves.last() {
+                if !previous.can_precede(m) {
+                    continue;
+                }
+                //Moves on opposite faces commute so only search them in one order
+                if previous.get_color() == m.get_opposite_color()
+                    && previous.get_color() > m.get_color()
+                {
+                    continue;
+                }
+            }
+
+            let next = cube.after_move(m, &self.data_source.moves_source);
+
+            if let Some(lower_bound) = self.get_lower_bound(&next) {
+                if depth + 1 + lower_bound <= bound {
+                    moves.push(m);
+                    if self.search(&next, depth + 1, bound, moves) {
+                        return true;
+                    }
+                    moves.pop();
+                }
+            }
+        }
+
+        false
+    }
+}
+
+/// Where each of the 24 corner stickers and 24 edge stickers has moved to
+type Stickers = [u8; 48];
+
+const IDENTITY: Stickers = {
+    let mut stickers = [0; 48];
+    let mut i = 0;
+    while i < 48 {
+        stickers[i] = i as u8;
+        i += 1;
+    }
+    stickers
+};
+
+/// Multiplying cubes composes their sticker permutations in the same order
+fn get_stickers(cube: &CubieCube) -> Stickers {
+    let mut stickers = [0; 48];
+
+    for i in 0..8 {
+        let corner = cube.corner_positions[i] as usize;
+        let orientation = cube.corner_orientations[i] as usize;
+        for t in 0..3 {
+            stickers[(i * 3) + t] = ((corner * 3) + ((t + orientation) % 3)) as u8;
+        }
+    }
+    for i in 0..12 {
+        let edge = cube.edge_positions[i] as usize;
+        let orientation = cube.edge_orientations[i] as usize;
+        for t in 0..2 {
+            stickers[24 + (i * 2) + t] = (24 + (edge * 2) + ((t + orientation) % 2)) as u8;
+        }
+    }
+
+    stickers
+}
+
+/// a after b
+fn compose(a: &Stickers, b: &Stickers) -> Stickers {
+    b.map(|x| a[x as usize])
+}
+
+fn invert(a: &Stickers) -> Stickers {
+    let mut inverse = [0; 48];
+    for (i, &x) in a.iter().enumerate() {
+        inverse[x as usize] = i as u8;
+    }
+    inverse
+}
+
+/// The group generated by some moves, acting on the stickers.
+/// Built with Knuth's version of the Schreier-Sims algorithm so membership can be tested exactly.
+struct StickerGroup {
+    /// At level k, a permutation which fixes the stickers before k and moves sticker k to each sticker it can reach
+    transversals: Vec<Vec<Option<Stickers>>>,
+    /// At level k, generators which fix the stickers before k
+    generators: Vec<Vec<Stickers>>,
+}
+
+impl StickerGroup {
+    fn new(generators: impl Iterator<Item = Stickers>) -> Self {
+        let mut group = Self {
+            transversals: (0..48)
+                .map(|k| {
+                    let mut transversal = vec![None; 48];
+                    transversal[k] = Some(IDENTITY);
+                    transversal
+                })
+                .collect_vec(),
+            generators: vec![Vec::new(); 48],
+        };
+
+        //The generators must all be at the first level for its orbit to be complete
+        for g in generators {
+            if !group.contains(&g) {
+                group.add_generator(0, g);
+            }
+        }
+        group
+    }
+
+    fn contains(&self, stickers: &Stickers) -> bool {
+        self.sift(0, *stickers).is_none()
+    }
+
+    /// Divides out the transversals from level k onwards.
+    /// Returns the level it got stuck at and what was left, or None if the permutation is in the group.
+    fn sift(&self, k: usize, mut stickers: Stickers) -> Option<(usize, Stickers)> {
+        for level in k..48 {
+            match &self.transversals[level][stickers[level] as usize] {
+                Some(t) => stickers = compose(&invert(t), &stickers),
+                None => return Some((level, stickers)),
+            }
+        }
+        None
+    }
+
+    fn sift_and_add(&mut self, k: usize, stickers: Stickers) {
+        if let Some((level, remainder)) = self.sift(k, stickers) {
+            self.add_generator(level, remainder);
+        }
+    }
+
+    fn add_generator(&mut self, k: usize, g: Stickers) {
+        self.generators[k].push(g);
+
+        let representatives = self.transversals[k].iter().flatten().copied().collect_vec();
+        for t in representatives {
+            self.add_coset(k, compose(&g, &t));
+        }
+    }
+
+    /// Records g as a transversal if it reaches a new sticker, otherwise sifts what it adds to the next level
+    fn add_coset(&mut self, k: usize, g: Stickers) {
+        let j = g[k] as usize;
+        match self.transversals[k][j] {
+            Some(t) => self.sift_and_add(k + 1, compose(&invert(&t), &g)),
+            None => {
+                self.transversals[k][j] = Some(g);
+                for s in self.generators[k].clone() {
+                    self.add_coset(k, compose(&s, &g));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::rc::Rc;
+
+use crate::core::prelude::*;
+
+/// A sequence of moves which scrambles a solved cube into a random state
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone)]
+pub struct Scramble {
+    /// The seed of the random state. Scrambling with this seed gives the same scramble.
+    pub seed: u64,
+    pub moves: Vec<Move>,
+}
+
+impl Scramble {
+    /// The state the cube is in after the scramble
+    pub fn get_cube(&self) -> CubieCube {
+        CubieCube::random_cube(self.seed)
+    }
+}
+
+impl std::fmt::Display for Scramble {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Move::format_sequence(&self.moves))
+    }
+}
+
+/// Creates scrambles for uniformly random states by solving the state and inverting the solution
+pub struct Scrambler {
+    data_source: Rc<DataSource>,
+    /// States whose solution has fewer moves than this are skipped
+    pub min_length: usize,
+    /// States whose solution has more moves than this are skipped.
+    /// The search stops as soon as it finds a solution this short.
+    pub max_length: usize,
+    /// The number of seeds to try before giving up
+    pub max_attempts: usize,
+}
+
+impl Scrambler {
+    pub fn new(data_source: Rc<DataSource>) -> Self {
+        Self {
+            data_source,
+            min_length: 19,
+            max_length: 21,
+            max_attempts: 100,
+        }
+    }
+
+    /// Creates a scramble from this seed.
+    /// If the solution is outside `min_length..=max_length`, or there is none, the following seeds are tried, and the returned seed is the one used.
+    /// Returns `None` if no seed worked within `max_attempts` tries.
+    pub fn scramble(&self, seed: u64) -> Option<Scramble> {
+        let mut seed = seed;
+
+        for _ in 0..self.max_attempts {
+            let cube = CubieCube::random_cube(seed);
+            let settings = SolveSettings {
+                stopping_length: (self.max_length + 1).min(u8::MAX.into()) as u8,
+                ..Default::default()
+            };
+
+            if let Some(solution) = Solver::get_solution(cube, self.data_source.clone(), settings) {
+                if (self.min_length..=self.max_length).contains(&solution.len()) {
+                    return Some(Scramble {
+                        seed,
+                        moves: Algorithm::from(solution).invert().into(),
+                    });
+                }
+            }
+
+            seed = seed.wrapping_add(1);
+        }
+
+        None
+    }
+
+    pub fn random_scramble(&self) -> Option<Scramble> {
+        self.scramble(rand::random())
+    }
+}
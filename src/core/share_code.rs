@@ -0,0 +1,283 @@
+use crate::core::prelude::*;
+
+use itertools::Itertools;
+use strum::EnumCount;
+
+/// The URL-safe base 64 alphabet used by share codes
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Each coordinate takes three characters as none are as large as 64^3
+const COORDINATE_CHARS: usize = 3;
+
+/// The name of each coordinate and the number of values it can take, in the order they are written
+const COORDINATE_RANGES: [(&str, u16); 6] = [
+    ("flip", 2048),
+    ("twist", 2187),
+    ("slice sorted", 11880),
+    ("corners", 40320),
+    ("u edges", 11880),
+    ("d edges", 11880),
+];
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ShareCodeError {
+    /// The code has the wrong number of characters
+    WrongLength {
+        expected: usize,
+        found: usize,
+    },
+    /// This character is not used in share codes. Index is 0-based.
+    UnknownCharacter {
+        character: char,
+        index: usize,
+    },
+    /// A coordinate is larger than any cube can have
+    CoordinateOutOfRange {
+        coordinate: &'static str,
+        value: u32,
+    },
+    /// The edge coordinates put more than one edge in the same position
+    OverlappingEdges,
+    /// The code describes a cube which can not be reached by turning the faces
+    InvalidCube(CubeError),
+    InvalidMove(MoveParseError),
+}
+
+impl std::fmt::Display for ShareCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShareCodeError::WrongLength { expected, found } => {
+                write!(f, "Expected {} characters but found {}", expected, found)
+            }
+            ShareCodeError::UnknownCharacter { character, index } => {
+                write!(f, "Unknown character '{}' at {}", character, index)
+            }
+            ShareCodeError::CoordinateOutOfRange { coordinate, value } => {
+                write!(f, "The {} coordinate {} is too large", coordinate, value)
+            }
+            ShareCodeError::OverlappingEdges => {
+                write!(f, "More than one edge is in the same position")
+            }
+            ShareCodeError::InvalidCube(error) => error.fmt(f),
+            ShareCodeError::InvalidMove(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ShareCodeError {}
+
+impl From<CubeError> for ShareCodeError {
+    fn from(error: CubeError) -> Self {
+        ShareCodeError::InvalidCube(error)
+    }
+}
+
+impl From<MoveParseError> for ShareCodeError {
+    fn from(error: MoveParseError) -> Self {
+        ShareCodeError::InvalidMove(error)
+    }
+}
+
+impl CoordinateCube {
+    fn to_array(&self) -> [u16; 6] {
+        [
+            self.flip,
+            self.twist,
+            self.slice_sorted,
+            self.corners,
+            self.u_edges,
+            self.d_edges,
+        ]
+    }
+}
+
+impl TryFrom<CoordinateCube> for CubieCube {
+    type Error = ShareCodeError;
+
+    fn try_from(coordinates: CoordinateCube) -> Result<Self, Self::Error> {
+        for ((coordinate, range), value) in COORDINATE_RANGES.iter().zip(coordinates.to_array()) {
+            if value >= *range {
+                return Err(ShareCodeError::CoordinateOutOfRange {
+                    coordinate,
+                    value: value.into(),
+                });
+            }
+        }
+
+        let mut cube = CubieCube::default();
+        cube.set_flip(coordinates.flip);
+        cube.set_twist(coordinates.twist);
+        cube.set_corners(coordinates.corners);
+
+        //Each edge setter places its own four edges and fills the other positions, so the three are combined
+        let mut u_edges = CubieCube::default();
+        u_edges.set_u_edges(coordinates.u_edges);
+        let mut d_edges = CubieCube::default();
+        d_edges.set_d_edges(coordinates.d_edges);
+        let mut slice_edges = CubieCube::default();
+        slice_edges.set_slice_sorted(coordinates.slice_sorted);
+
+        for position in 0..EdgePosition::COUNT {
+            let placed = [
+                (
+                    u_edges.edge_positions[position],
+                    EdgePosition::Ur..=EdgePosition::Ub,
+                ),
+                (
+                    d_edges.edge_positions[position],
+                    EdgePosition::Dr..=EdgePosition::Db,
+                ),
+                (
+                    slice_edges.edge_positions[position],
+                    EdgePosition::Fr..=EdgePosition::Br,
+                ),
+            ]
+            .into_iter()
+            .filter(|(edge, group)| group.contains(edge))
+            .map(|(edge, _)| edge)
+            .collect_vec();
+
+            match placed.as_slice() {
+                [edge] => cube.edge_positions[position] = *edge,
+                _ => return Err(ShareCodeError::OverlappingEdges),
+            }
+        }
+
+        cube.verify()?;
+        Ok(cube)
+    }
+}
+
+impl CubieCube {
+    /// An 18 character URL-safe code for the cube, made from its coordinates
+    pub fn to_share_code(&self) -> String {
+        CoordinateCube::from(self.clone())
+            .to_array()
+            .into_iter()
+            .flat_map(|value| encode_number(value.into(), COORDINATE_CHARS))
+            .collect()
+    }
+
+    pub fn from_share_code(code: &str) -> Result<Self, ShareCodeError> {
+        let digits = decode_digits(code, COORDINATE_RANGES.len() * COORDINATE_CHARS)?;
+        let mut values = [0u16; 6];
+        for ((value, chunk), (coordinate, _)) in values
+            .iter_mut()
+            .zip(digits.chunks(COORDINATE_CHARS))
+            .zip(COORDINATE_RANGES)
+        {
+            let number = chunk.iter().fold(0u32, |acc, d| acc * 64 + *d as u32);
+            *value = u16::try_from(number).map_err(|_| ShareCodeError::CoordinateOutOfRange {
+                coordinate,
+                value: number,
+            })?;
+        }
+
+        CubieCube::try_from(CoordinateCube {
+            flip: values[0],
+            twist: values[1],
+            slice_sorted: values[2],
+            corners: values[3],
+            u_edges: values[4],
+            d_edges: values[5],
+        })
+    }
+}
+
+impl FaceletCube {
+    /// A 27 character URL-safe code for the facelets. Each character holds two facelets, which may be unset.
+    pub fn to_share_code(&self) -> String {
+        self.facelets
+            .iter()
+            .map(|f| f.map_or(0, |color| color as u32 + 1))
+            .tuples()
+            .map(|(a, b)| ALPHABET[(a * 7 + b) as usize] as char)
+            .collect()
+    }
+
+    pub fn from_share_code(code: &str) -> Result<Self, ShareCodeError> {
+        let digits = decode_digits(code, 27)?;
+        let mut facelets = [None; 54];
+
+        for (index, digit) in digits.into_iter().enumerate() {
+            if digit >= 49 {
+                return Err(ShareCodeError::UnknownCharacter {
+                    character: ALPHABET[digit as usize] as char,
+                    index,
+                });
+            }
+            facelets[index * 2] = decode_facelet(digit / 7);
+            facelets[index * 2 + 1] = decode_facelet(digit % 7);
+        }
+
+        Ok(FaceletCube { facelets })
+    }
+}
+
+impl Move {
+    /// A URL-safe code for the moves, using the numeric notation without spaces e.g. "R1U2F3"
+    pub fn to_share_code(moves: &[Move]) -> String {
+        moves.iter().map(|m| m.to_string()).join("")
+    }
+
+    pub fn from_share_code(code: &str) -> Result<Vec<Move>, ShareCodeError> {
+        let chars = code.chars().collect_vec();
+
+        let moves = chars
+            .chunks(2)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let token: String = chunk.iter().collect();
+                let column = i * 2 + 1;
+                if chunk.len() != 2 {
+                    return Err(MoveParseError::BadSuffix { token, column });
+                }
+
+                //The column is of the token within the whole code
+                token.parse::<Move>().map_err(|e| match e {
+                    MoveParseError::UnknownFace { token, .. } => {
+                        MoveParseError::UnknownFace { token, column }
+                    }
+                    MoveParseError::BadSuffix { token, .. } => {
+                        MoveParseError::BadSuffix { token, column }
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(moves)
+    }
+}
+
+fn encode_number(mut value: u32, length: usize) -> Vec<char> {
+    let mut chars = vec!['A'; length];
+    for c in chars.iter_mut().rev() {
+        *c = ALPHABET[(value % 64) as usize] as char;
+        value /= 64;
+    }
+    chars
+}
+
+fn decode_digits(code: &str, length: usize) -> Result<Vec<u8>, ShareCodeError> {
+    let found = code.chars().count();
+    if found != length {
+        return Err(ShareCodeError::WrongLength {
+            expected: length,
+            found,
+        });
+    }
+
+    code.chars()
+        .enumerate()
+        .map(|(index, character)| {
+            ALPHABET
+                .iter()
+                .position(|&c| c as char == character)
+                .map(|d| d as u8)
+                .ok_or(ShareCodeError::UnknownCharacter { character, index })
+        })
+        .collect()
+}
+
+fn decode_facelet(value: u8) -> Option<FaceColor> {
+    value.checked_sub(1).and_then(FaceColor::from_repr)
+}
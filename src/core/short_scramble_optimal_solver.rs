@@ -0,0 +1,192 @@
+use std::rc::Rc;
+
+use crate::core::prelude::*;
+
+use log::debug;
+
+/// A solution which is known to be as short as possible in the half turn metric
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OptimalSolution {
+    pub moves: Vec<Move>,
+    /// The proven minimum number of moves needed to solve the cube
+    pub length: u8,
+}
+
+/// Finds provably shortest solutions for cubes close to solved, such as short scrambles, using IDA*.
+/// It is not a general optimal solver: the lower bound is only the greatest of the phase 1 depth,
+/// looked at from three directions, and the corner depth, with no edge pattern database.
+/// Cubes needing up to about 14 moves are solved in under a second, but 15 moves takes about a minute in release builds,
+/// and each further move multiplies the time by roughly 13. Most random cubes need 18 moves, so are out of reach.
+pub struct ShortScrambleOptimalSolver {
+    data_source: Rc<DataSource>,
+    corner_twist_depth: Vec<u8>,
+    /// For each rotation, the move in the rotated frame which corresponds to each move
+    rotated_moves: [[Move; 18]; 3],
+    solved: CoordinateCube,
+}
+
+/// The coordinates of the cube as seen from the three rotations around the URF-DBL diagonal
+#[derive(Clone)]
+struct OptimalSearchNode {
+    cubes: [CoordinateCube; 3],
+    phase_1_depths: [Option<u8>; 3],
+}
+
+impl ShortScrambleOptimalSolver {
+    /// The depth that unknown entries in a quick phase 1 table are known to be at least
+    const UNKNOWN_PHASE_1_DEPTH: u8 = 9;
+
+    pub fn new(data_source: Rc<DataSource>) -> Self {
+        let corner_twist_depth = DataSource::create_corner_twist_depth(
+            &data_source.moves_source,
+            &data_source.corners_source,
+        );
+
+        let mut rotated_moves = [Move::ALLMOVES; 3];
+        for (rotation, moves) in rotated_moves.iter_mut().enumerate() {
+            for m in Move::ALLMOVES {
+                moves[m as usize] = Move::ALLMOVES
+                    .into_iter()
+                    .find(|r| r.rotate(rotation as u8) == m)
+                    .unwrap();
+            }
+        }
+
+        Self {
+            data_source,
+            corner_twist_depth,
+            rotated_moves,
+            solved: CubieCube::default().into(),
+        }
+    }
+
+    /// Finds a shortest solution with at most max_length moves.
+    /// Use a small max_length to bound the time spent on cubes which are far from solved.
+    pub fn solve(&self, cube: &CubieCube, max_length: u8) -> Option<OptimalSolution> {
+        let root = self.create_node(cube);
+        let mut bound = self.get_lower_bound(&root);
+        let mut moves: Vec<Move> = Vec::new();
+
+        while bound <= max_length {
+            debug!("Searching for optimal solution with {} moves", bound);
+            if self.search(&root, 0, bound, &mut moves) {
+                return Some(OptimalSolution {
+                    moves,
+                    length: bound,
+                });
+            }
+            bound += 1;
+        }
+
+        None
+    }
+
+    fn create_node(&self, cube: &CubieCube) -> OptimalSearchNode {
+        let rotated = [
+            cube.clone(),
+            URF3_SYMMETRY
+                .multiply(cube)
+                .multiply(&URF3_SYMMETRY)
+                .multiply(&URF3_SYMMETRY),
+            URF3_SYMMETRY
+                .multiply(&URF3_SYMMETRY)
+                .multiply(cube)
+                .multiply(&URF3_SYMMETRY),
+        ];
+
+        let cubes = rotated.map(CoordinateCube::from);
+        let phase_1_depths = cubes.clone().map(|c| {
+            self.data_source
+                .get_phase_1_depth(c.flip, c.twist, c.slice_sorted)
+        });
+
+        OptimalSearchNode {
+            cubes,
+            phase_1_depths,
+        }
+    }
+
+    fn after_move(&self, node: &OptimalSearchNode, m: Move) -> OptimalSearchNode {
+        let mut cubes = node.cubes.clone();
+        let mut phase_1_depths = node.phase_1_depths;
+
+        for rotation in 0..3 {
+            let rotated_move = self.rotated_moves[rotation][m as usize];
+            let cube = cubes[rotation].after_move(rotated_move, &self.data_source.moves_source);
+
+            let depth_mod3 = self.data_source.get_flip_slice_twist_depth_mod_3(
+                cube.flip,
+                cube.twist,
+                cube.slice_sorted,
+            );
+
+            //Adjacent cubes differ in depth by at most one, so the depth mod 3 determines the new depth
+            phase_1_depths[rotation] = match (depth_mod3, phase_1_depths[rotation]) {
+                (None, _) => None,
+                (Some(d3), Some(previous)) => [previous.saturating_sub(1), previous, previous + 1]
+                    .into_iter()
+                    .find(|d| d % 3 == d3),
+                (Some(_), None) => {
+                    self.data_source
+                        .get_phase_1_depth(cube.flip, cube.twist, cube.slice_sorted)
+                }
+            };
+            cubes[rotation] = cube;
+        }
+
+        OptimalSearchNode {
+            cubes,
+            phase_1_depths,
+        }
+    }
+
+    fn get_lower_bound(&self, node: &OptimalSearchNode) -> u8 {
+        let corner_depth = self.data_source.get_corner_twist_depth(
+            &self.corner_twist_depth,
+            node.cubes[0].corners,
+            node.cubes[0].twist,
+        );
+
+        node.phase_1_depths
+            .into_iter()
+            .map(|d| d.unwrap_or(Self::UNKNOWN_PHASE_1_DEPTH))
+            .fold(corner_depth, u8::max)
+    }
+
+    fn search(
+        &self,
+        node: &OptimalSearchNode,
+        depth: u8,
+        bound: u8,
+        moves: &mut Vec<Move>,
+    ) -> bool {
+        if depth == bound {
+            return node.cubes[0] == self.solved;
+        }
+
+        for m in Move::ALLMOVES {
+            if let Some(&previous) = moves.last() {
+                if !previous.can_precede(m) {
+                    continue;
+                }
+                //Moves on opposite faces commute so only search them in one order
+                if previous.get_color() == m.get_opposite_color()
+                    && previous.get_color() > m.get_color()
+                {
+                    continue;
+                }
+            }
+
+            let next = self.after_move(node, m);
+            if depth + 1 + self.get_lower_bound(&next) <= bound {
+                moves.push(m);
+                if self.search(&next, depth + 1, bound, moves) {
+                    return true;
+                }
+                moves.pop();
+            }
+        }
+
+        false
+    }
+}
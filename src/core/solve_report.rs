@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use crate::core::prelude::*;
+
+use itertools::Itertools;
+
+/// Statistics about a solve, for tuning the search
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone, Default)]
+pub struct SolveReport {
+    /// The number of search states taken from the queue
+    pub nodes_expanded: usize,
+    /// The largest the queue got
+    pub max_queue_size: usize,
+    /// The number of distinct cubes reached
+    pub seen_size: usize,
+    /// States expanded in phase 1
+    pub phase_1: PhaseStatistics,
+    /// States expanded in phase 2
+    pub phase_2: PhaseStatistics,
+    /// Which of the six starting searches found the best solution
+    pub winning_start: Option<SearchStart>,
+    /// Each time a better solution was found, in order
+    pub improvements: Vec<SolutionImprovement>,
+    pub elapsed: Duration,
+}
+
+/// The work done expanding states in one phase
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone, Copy, Default)]
+pub struct PhaseStatistics {
+    pub nodes: usize,
+    /// Estimated by timing one node in every 64
+    pub time: Duration,
+}
+
+/// The rotation and inversion of the cube a search started from
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone, Copy)]
+pub struct SearchStart {
+    pub inverted: bool,
+    /// The number of times the cube was rotated around the URF corner
+    pub rotation: u8,
+}
+
+/// A solution which was better than the solutions before it
+#[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, Clone, Copy)]
+pub struct SolutionImprovement {
+    /// The cost of the solution in the chosen metric
+    pub cost: u8,
+    /// The iteration it was found in, counted separately by each search
+    pub iteration: usize,
+    pub elapsed: Duration,
+}
+
+impl SolveReport {
+    /// Combines the reports of searches which ran at the same time.
+    /// The counts are summed, the largest queue is the largest of any search, and the improvements are those which beat every earlier solution from any search.
+    pub fn merge(reports: impl IntoIterator<Item = SolveReport>) -> Self {
+        let mut merged = SolveReport::default();
+        let mut improvements: Vec<SolutionImprovement> = Vec::new();
+
+        for report in reports {
+            merged.nodes_expanded += report.nodes_expanded;
+            merged.max_queue_size = merged.max_queue_size.max(report.max_queue_size);
+            merged.seen_size += report.seen_size;
+            merged.phase_1.nodes += report.phase_1.nodes;
+            merged.phase_1.time += report.phase_1.time;
+            merged.phase_2.nodes += report.phase_2.nodes;
+            merged.phase_2.time += report.phase_2.time;
+            merged.elapsed = merged.elapsed.max(report.elapsed);
+            improvements.extend(report.improvements);
+        }
+
+        improvements.sort_by_key(|i| i.elapsed);
+        for improvement in improvements {
+            if merged
+                .improvements
+                .last()
+                .is_none_or(|last| improvement.cost < last.cost)
+            {
+                merged.improvements.push(improvement);
+            }
+        }
+
+        merged
+    }
+
+    /// Counts an expanded node, adding its time if it was timed
+    pub(crate) fn record_node(&mut self, phase_data: &PhaseData, time: Option<Duration>) {
+        self.nodes_expanded += 1;
+
+        let statistics = match phase_data {
+            PhaseData::Phase1 { .. } => &mut self.phase_1,
+            PhaseData::Phase2 { .. } => &mut self.phase_2,
+            PhaseData::Solved => return,
+        };
+        statistics.nodes += 1;
+        if let Some(time) = time {
+            statistics.time += time;
+        }
+    }
+}
+
+impl std::fmt::Display for SolveReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Time: {:?}", self.elapsed)?;
+        writeln!(
+            f,
+            "Nodes expanded: {} (phase 1: {} in {:?}, phase 2: {} in {:?})",
+            self.nodes_expanded,
+            self.phase_1.nodes,
+            self.phase_1.time,
+            self.phase_2.nodes,
+            self.phase_2.time
+        )?;
+        writeln!(f, "Largest queue: {}", self.max_queue_size)?;
+        writeln!(f, "Cubes seen: {}", self.seen_size)?;
+        if let Some(start) = self.winning_start {
+            writeln!(
+                f,
+                "Winning start: rotation {}{}",
+                start.rotation,
+                if start.inverted { ", inverted" } else { "" }
+            )?;
+        }
+        write!(
+            f,
+            "Improvements: {}",
+            self.improvements
+                .iter()
+                .map(|i| format!("{} at {:?}", i.cost, i.elapsed))
+                .join(", ")
+        )
+    }
+}
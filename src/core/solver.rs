@@ -1,8 +1,12 @@
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 
+use std::ops::Deref;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::core::prelude::*;
 
@@ -12,9 +16,18 @@ use strum::IntoEnumIterator;
 
 use log::debug;
 
+use instant::Instant;
+
+#[derive(Clone)]
 pub struct SolveSettings {
+    /// Stop searching once a solution shorter than this is found. Measured in the chosen metric.
     pub stopping_length: u8,
     pub max_iterations: usize,
+    pub metric: Metric,
+    /// Stop searching after this long and return the best solution found so far
+    pub time_budget: Option<Duration>,
+    /// Stop searching once this is cancelled and return the best solution found so far
+    pub cancellation: Option<CancellationToken>,
 }
 
 impl Default for SolveSettings {
@@ -22,10 +35,66 @@ impl Default for SolveSettings {
         Self {
             stopping_length: 20,
             max_iterations: 10000,
+            metric: Metric::default(),
+            time_budget: None,
+            cancellation: None,
         }
     }
 }
 
+/// A shared flag which stops a solve when it is cancelled
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed)
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Why the solver stopped searching
+#[derive(Debug, PartialEq, Eq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub enum StopReason {
+    /// A solution shorter than the stopping length was found
+    FoundTarget,
+    /// Every search state was tried, so the solution is the best the solver can find
+    Exhausted,
+    /// The maximum number of iterations was reached
+    IterationLimit,
+    /// The time budget ran out
+    TimedOut,
+    /// The cancellation token was cancelled
+    Cancelled,
+}
+
+/// The best solution the solver found and why it stopped looking for a better one
+#[derive(Debug, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SolveOutcome {
+    pub solution: Option<Vec<Move>>,
+    pub stop_reason: StopReason,
+    pub report: SolveReport,
+}
+
+/// Which solutions to collect when searching for more than one
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum SolutionLimit {
+    /// Only keep the best solution
+    #[default]
+    Best,
+    /// Keep the N shortest distinct solutions
+    Shortest(usize),
+    /// Keep every distinct solution up to this length, in the chosen metric
+    UpToLength(u8),
+}
+
 pub struct Solver {}
 
 impl Solver {
@@ -34,7 +103,73 @@ impl Solver {
         data_source: Rc<DataSource>,
         settings: SolveSettings,
     ) -> Option<Vec<Move>> {
-        let mut queue: BinaryHeap<SearchState> = BinaryHeap::new();
+        Self::solve(base_cube, data_source, settings).solution
+    }
+
+    /// Gets the best solution found before the solver stopped, and the reason it stopped
+    pub fn solve<D: Deref<Target = DataSource>>(
+        base_cube: CubieCube,
+        data_source: D,
+        settings: SolveSettings,
+    ) -> SolveOutcome {
+        let mut coordinator =
+            Self::create_coordinator(base_cube, data_source, &settings, SolutionLimit::Best);
+
+        let stop_reason = coordinator.solve(&settings);
+
+        SolveOutcome {
+            solution: coordinator
+                .solution
+                .map(|s| Algorithm::from(s.get_moves()).simplify().into()),
+            stop_reason,
+            report: coordinator.report,
+        }
+    }
+
+    /// Finds moves which transform the start cube into the target cube
+    pub fn get_solution_between(
+        start: CubieCube,
+        target: CubieCube,
+        data_source: Rc<DataSource>,
+        settings: SolveSettings,
+    ) -> Option<Vec<Move>> {
+        //start * moves = target, so moves = start⁻¹ * target which is the solution of target⁻¹ * start
+        let cube = target.invert().multiply(&start);
+        Self::get_solution(cube, data_source, settings)
+    }
+
+    /// Gets several distinct solutions, shortest first.
+    /// Solutions which only differ by the order of moves on opposite faces are counted as the same solution.
+    pub fn get_solutions(
+        base_cube: CubieCube,
+        data_source: Rc<DataSource>,
+        settings: SolveSettings,
+        limit: SolutionLimit,
+    ) -> Vec<Vec<Move>> {
+        let mut coordinator = Self::create_coordinator(base_cube, data_source, &settings, limit);
+
+        coordinator.solve(&settings);
+
+        coordinator
+            .solutions
+            .iter()
+            .map(|s| Algorithm::from(s.get_moves()).simplify().into())
+            .collect_vec()
+    }
+
+    fn create_coordinator<D: Deref<Target = DataSource>>(
+        base_cube: CubieCube,
+        data_source: D,
+        settings: &SolveSettings,
+        limit: SolutionLimit,
+    ) -> SerialSolveCoordinator<D> {
+        let starts = Self::create_start_states(&base_cube, &data_source);
+        SerialSolveCoordinator::new(data_source, starts, settings, limit)
+    }
+
+    /// The six states the search starts from: the cube in each of three rotations, and their inverses
+    pub fn create_start_states(base_cube: &CubieCube, data_source: &DataSource) -> Vec<SearchState> {
+        let mut starts: Vec<SearchState> = Vec::new();
 
         for rotation in 0u8..=2u8 {
             for inv_i in 0..=1 {
@@ -43,12 +178,12 @@ impl Solver {
                 let rotated_cube = match rotation {
                     0 => base_cube.clone(),
                     1 => URF3_SYMMETRY
-                        .multiply(&base_cube)
+                        .multiply(base_cube)
                         .multiply(&URF3_SYMMETRY)
                         .multiply(&URF3_SYMMETRY),
                     _ => URF3_SYMMETRY
                         .multiply(&URF3_SYMMETRY)
-                        .multiply(&base_cube)
+                        .multiply(base_cube)
                         .multiply(&URF3_SYMMETRY),
                 };
 
@@ -58,29 +193,20 @@ impl Solver {
                     rotated_cube.into()
                 };
 
-                let phase_data = cube.create_phase_data(&data_source);
+                let phase_data = cube.create_phase_data(data_source);
 
-                queue.push(SearchState {
+                starts.push(SearchState {
                     cube,
                     phase_data,
                     moves: 0,
+                    cost: 0,
                     previous: PreviousState::Start { inverted, rotation },
                     deepening: false,
                 });
             }
         }
 
-        let mut coordinator = SerialSolveCoordinator {
-            data_source,
-            solution: None,
-            seen: HashMap::new(),
-            max_moves: None,
-            queue,
-        };
-
-        coordinator
-            .solve(settings.stopping_length, settings.max_iterations)
-            .map(|s| s.get_moves())
+        starts
     }
 }
 
@@ -89,6 +215,8 @@ pub struct SearchState {
     cube: CoordinateCube,
     phase_data: PhaseData,
     moves: u8,
+    /// The cost of the moves so far, in the coordinator's metric
+    cost: u8,
     previous: PreviousState,
     deepening: bool,
 }
@@ -135,9 +263,31 @@ impl SearchState {
         }
     }
 
+    /// The rotation and inversion of the search this state came from
+    pub fn get_start(&self) -> SearchStart {
+        let mut prev = &self.previous;
+
+        loop {
+            match prev {
+                PreviousState::Start { inverted, rotation } => {
+                    return SearchStart {
+                        inverted: *inverted,
+                        rotation: *rotation,
+                    }
+                }
+                PreviousState::Move { state, m: _ } => prev = &state.previous,
+            }
+        }
+    }
+
+    /// The cost of the moves so far, in the metric being used
+    pub fn get_cost(&self) -> u8 {
+        self.cost
+    }
+
     /// Gets the priority of this solution.
     /// Cubes closer to being solved have higher priority
-    /// Solutions with more moves have lower priority
+    /// Solutions with a higher cost have lower priority
     pub fn get_priority(&self) -> u8 {
         match self.phase_data {
             PhaseData::Phase1 {
@@ -146,12 +296,12 @@ impl SearchState {
 
                 if fsm.is_some(){
                     if self.deepening {
-                        100 - self.moves.min(40)
+                        100 - self.cost.min(40)
                     } else {
-                        50 - self.moves.min(40)
+                        50 - self.cost.min(40)
                     }
                 }else{
-                    10 - self.moves.min(10)
+                    10 - self.cost.min(10)
                 }
 
                
@@ -159,12 +309,15 @@ impl SearchState {
             PhaseData::Phase2 {
                 cornslice_depth,
                 corners_ud_edges_depth_mod3: _,
-            } => 200 - (2 * cornslice_depth.min(12)) - self.moves.min(40),
-            PhaseData::Solved => u8::MAX - self.moves.min(50),
+            } => 200 - (2 * cornslice_depth.min(12)) - self.cost.min(40),
+            PhaseData::Solved => u8::MAX - self.cost.min(50),
         }
     }
 
-    pub fn iterate(self, coordinator: &mut SerialSolveCoordinator) -> Option<Self> {
+    pub fn iterate<D: Deref<Target = DataSource>>(
+        self,
+        coordinator: &mut SerialSolveCoordinator<D>,
+    ) -> Option<Self> {
         match self.phase_data {
             PhaseData::Phase1 {
                 flip_slice_twist_depth_mod3,
@@ -200,6 +353,7 @@ impl SearchState {
                             cube: next_cube,
                             phase_data: next_phase,
                             moves: self.moves + 1,
+                            cost: self.cost + coordinator.metric.get_cost(m, self.get_pairable_move()),
                             previous: PreviousState::Move {
                                 state: next_previous_state.clone(),
                                 m,
@@ -217,8 +371,20 @@ impl SearchState {
                 cornslice_depth,
                 corners_ud_edges_depth_mod3,
             } => {
-                if coordinator.max_moves.is_some() && cornslice_depth >= (coordinator.max_moves.unwrap() - self.moves).min(11) {
-                    return None;
+                if let Some(max_moves) = coordinator.max_moves {
+                    if coordinator.metric.lower_bound(cornslice_depth)
+                        >= max_moves.saturating_sub(self.cost).min(11)
+                    {
+                        return None;
+                    }
+                }
+                if let Some(shared_best) = &coordinator.shared_best {
+                    //Only prune states which can not beat another search's solution
+                    let lower_bound =
+                        self.cost.saturating_add(coordinator.metric.lower_bound(cornslice_depth));
+                    if lower_bound >= shared_best.load(Ordering::Relaxed) {
+                        return None;
+                    }
                 }
 
                 let next_depth = (corners_ud_edges_depth_mod3 + 2) % 3;
@@ -249,6 +415,7 @@ impl SearchState {
                             cube: next_cube,
                             phase_data: next_phase,
                             moves: self.moves + 1,
+                            cost: self.cost + coordinator.metric.get_cost(m, self.get_pairable_move()),
                             previous: PreviousState::Move {
                                 state: next_previous_state.clone(),
                                 m,
@@ -265,6 +432,15 @@ impl SearchState {
         }
     }
 
+    /// The last move, if it could combine with the next move to make a slice move.
+    /// A move which already completed a slice move cannot be paired again.
+    fn get_pairable_move(&self) -> Option<Move> {
+        match &self.previous {
+            PreviousState::Move { state, m } if self.cost > state.cost => Some(*m),
+            _ => None,
+        }
+    }
+
     fn can_do_move(&self, m: Move) -> bool {
         if let PreviousState::Move { state: _, m: mprev } = self.previous {
             if !mprev.can_precede(m) {
@@ -281,68 +457,204 @@ pub enum PreviousState {
     Move { state: Arc<SearchState>, m: Move },
 }
 
-pub struct SerialSolveCoordinator {
-    pub data_source: Rc<DataSource>,
+pub struct SerialSolveCoordinator<D: Deref<Target = DataSource> = Rc<DataSource>> {
+    pub data_source: D,
     pub solution: Option<SearchState>,
+    /// The maximum cost of a new solution, in the chosen metric
     pub max_moves: Option<u8>,
     pub queue: BinaryHeap<SearchState>,
     pub seen: HashMap<CoordinateCube, u8>,
+    pub metric: Metric,
+    pub limit: SolutionLimit,
+    /// The distinct solutions found so far, ordered by cost. Only used if the limit is not `SolutionLimit::Best`
+    pub solutions: Vec<SearchState>,
+    /// The simplified moves of every solution added to `solutions`, to find duplicates.
+    /// Simplifying puts commuting opposite face turns in a fixed order, so solutions which only differ by that are the same.
+    solution_moves: HashSet<Vec<Move>>,
+    pub report: SolveReport,
+    /// The cost of the best solution found by any coordinator searching the same cube, or u8::MAX if there is none.
+    /// Used to prune and to stop when several coordinators search in parallel.
+    pub shared_best: Option<Arc<AtomicU8>>,
 }
 
-impl SerialSolveCoordinator {
-    pub fn solve(&mut self, stopping_length: u8, max_iterations: usize) -> Option<SearchState> {
+impl<D: Deref<Target = DataSource>> SerialSolveCoordinator<D> {
+    pub fn new(
+        data_source: D,
+        starts: Vec<SearchState>,
+        settings: &SolveSettings,
+        limit: SolutionLimit,
+    ) -> Self {
+        let max_moves = match limit {
+            SolutionLimit::UpToLength(length) => Some(length.saturating_add(1)),
+            _ => None,
+        };
+
+        Self {
+            data_source,
+            solution: None,
+            seen: HashMap::new(),
+            max_moves,
+            queue: starts.into_iter().collect(),
+            metric: settings.metric,
+            limit,
+            solutions: Vec::new(),
+            solution_moves: HashSet::new(),
+            report: SolveReport::default(),
+            shared_best: None,
+        }
+    }
+
+    /// Checking the clock is slow on the web so the time budget is only checked, and nodes only timed, this often
+    const CLOCK_CHECK_INTERVAL: usize = 64;
+
+    /// Searches until one of the settings' stopping conditions is met.
+    /// The best solution found is in `self.solution` and statistics about the search are in `self.report`.
+    pub fn solve(&mut self, settings: &SolveSettings) -> StopReason {
+        let start = Instant::now();
+        let stop_reason = self.search(settings, start);
+
+        self.report.elapsed = start.elapsed();
+        self.report.seen_size = self.seen.len();
+        self.report.winning_start = self.solution.as_ref().map(|s| s.get_start());
+
+        stop_reason
+    }
+
+    fn search(&mut self, settings: &SolveSettings, start: Instant) -> StopReason {
+        let deadline = settings.time_budget.map(|budget| start + budget);
         let mut iterations = 0;
-        while iterations < max_iterations {
+        while iterations < settings.max_iterations {
+            if settings.cancellation.as_ref().is_some_and(|c| c.is_cancelled()) {
+                debug!("Cancelled after {:?} iterations", iterations);
+                return StopReason::Cancelled;
+            }
+            if iterations % Self::CLOCK_CHECK_INTERVAL == 0
+                && deadline.is_some_and(|d| Instant::now() >= d)
+            {
+                debug!("Ran out of time after {:?} iterations", iterations);
+                return StopReason::TimedOut;
+            }
+            if let Some(shared_best) = &self.shared_best {
+                let best = shared_best.load(Ordering::Relaxed);
+                if best < settings.stopping_length {
+                    return StopReason::FoundTarget;
+                }
+            }
+
             if let Some(next) = self.queue.pop() {
-                if let Some(solution) = next.iterate(self) {
+                let phase_data = next.phase_data.clone();
+                let iteration_start =
+                    (iterations % Self::CLOCK_CHECK_INTERVAL == 0).then(Instant::now);
+                let result = next.iterate(self);
+                self.report.record_node(
+                    &phase_data,
+                    iteration_start.map(|i| i.elapsed() * Self::CLOCK_CHECK_INTERVAL as u32),
+                );
+                self.report.max_queue_size = self.report.max_queue_size.max(self.queue.len());
+
+                if let Some(solution) = result {
                     if self.try_add_solution(solution.clone()) {
                         debug!(
-                            "Solved with {} moves in {:?} iterations",
-                            solution.moves, iterations
+                            "Solved with {} moves (cost {}) in {:?} iterations",
+                            solution.moves, solution.cost, iterations
                         );
+                        //Other limits also add solutions which are no better than the best so far
+                        if self
+                            .report
+                            .improvements
+                            .last()
+                            .is_none_or(|last| solution.cost < last.cost)
+                        {
+                            self.report.improvements.push(SolutionImprovement {
+                                cost: solution.cost,
+                                iteration: iterations,
+                                elapsed: start.elapsed(),
+                            });
+                        }
 
-                        if solution.moves < stopping_length {
-                            return Some(solution);
+                        if self.limit == SolutionLimit::Best
+                            && solution.cost < settings.stopping_length
+                        {
+                            return StopReason::FoundTarget;
                         }
                     }
                 }
             } else {
                 debug!("Solved in {:?} iterations", iterations);
-                return self.solution.clone();
+                return StopReason::Exhausted;
             }
 
             iterations += 1;
         }
-        match self.solution.clone() {
-            Some(s) => {
-                debug!(
-                    "Could not find better solution in {:?} iterations",
-                    iterations
-                );
-                Some(s)
-            }
-            None => {
-                debug!("Failed to solve in {:?} iterations", iterations);
-                None
-            }
+        match self.solution {
+            Some(_) => debug!(
+                "Could not find better solution in {:?} iterations",
+                iterations
+            ),
+            None => debug!("Failed to solve in {:?} iterations", iterations),
         }
+        StopReason::IterationLimit
     }
 
     fn try_add_solution(&mut self, state: SearchState) -> bool {
+        if self.limit != SolutionLimit::Best {
+            return self.try_add_alternative_solution(state);
+        }
+
         if let Some(current) = &self.solution {
-            if current.moves <= state.moves {
+            if current.cost <= state.cost {
                 return false;
             }
         }
 
-        self.max_moves = Some(state.moves - 1);
+        self.max_moves = Some(state.cost.saturating_sub(1));
+        if let Some(shared_best) = &self.shared_best {
+            shared_best.fetch_min(state.cost, Ordering::Relaxed);
+        }
         self.solution = Some(state);
         true
     }
 
+    fn try_add_alternative_solution(&mut self, state: SearchState) -> bool {
+        if let SolutionLimit::UpToLength(length) = self.limit {
+            if state.cost > length {
+                return false;
+            }
+        }
+
+        let simplified = Algorithm::from(state.get_moves()).simplify().into();
+        if !self.solution_moves.insert(simplified) {
+            return false;
+        }
+
+        let index = self.solutions.partition_point(|s| s.cost <= state.cost);
+        self.solutions.insert(index, state);
+
+        if let SolutionLimit::Shortest(n) = self.limit {
+            if self.solutions.len() > n {
+                self.solutions.truncate(n);
+                if index >= n {
+                    return false;
+                }
+            }
+            if self.solutions.len() == n {
+                self.max_moves = self.solutions.last().map(|s| s.cost);
+            }
+        }
+
+        self.solution = self.solutions.first().cloned();
+        true
+    }
+
     pub fn maybe_add_search(&mut self, state: SearchState) {
+        if self.limit != SolutionLimit::Best && state.phase_data == PhaseData::Solved {
+            //Every solution reaches the same cube so they must not be deduplicated here
+            self.queue.push(state);
+            return;
+        }
+
         let cube = state.cube.clone();
-        let moves = state.moves;
+        let moves = state.cost;
 
         loop {
             let insert_result = self.seen.try_insert(cube.clone(), moves);
@@ -352,8 +664,12 @@ impl SerialSolveCoordinator {
                     self.queue.push(state);
                     return;
                 }
-                Err(entry) => {
-                    if entry.value <= moves {
+                Err(error) => {
+                    //The error's value is the one which was not inserted, so compare with the entry
+                    let previous = *error.entry.get();
+                    //Other limits keep equal routes, as they may lead to distinct solutions
+                    if previous < moves || (previous == moves && self.limit == SolutionLimit::Best)
+                    {
                         return; //The previous route was as good as or better than this
                     } else {
                         self.seen.remove_entry(&cube.clone()); //This was a better path. Try inserting it again
@@ -2,5 +2,3 @@
 #![feature(map_try_insert)]
 
 pub mod core;
-pub mod state;
-pub mod web;
@@ -1,4 +1,5 @@
 use std::rc::Rc;
+use std::sync::Arc;
 
 use itertools::Itertools;
 
@@ -182,19 +183,19 @@ fn test_ud_edges(seed: u8) {
 
 #[test]
 fn test_u_edges() {
-    let move_source = MovesSource::create();
+    let move_source = MovesSource::create(&mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(move_source.u_edges_move);
 }
 
 #[test]
 fn test_d_edges() {
-    let move_source = MovesSource::create();
+    let move_source = MovesSource::create(&mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(move_source.d_edges_move);
 }
 
 #[test]
 fn test_ud_edges_data() {
-    let move_source = MovesSource::create();
+    let move_source = MovesSource::create(&mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(move_source.u_d_edges_move);
 }
 
@@ -206,49 +207,49 @@ fn test_create_corners() {
 
 #[test]
 fn test_corner_slice_depth() {
-    let moves_source = MovesSource::create();
+    let moves_source = MovesSource::create(&mut IgnoreProgress).unwrap();
 
-    let table = DataSource::create_corner_slice_depth(&moves_source);
+    let table = DataSource::create_corner_slice_depth(&moves_source, &mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(table);
 }
 
 #[test]
 fn test_phase_two_pruning() {
-    let moves_source = MovesSource::create();
-    let corner_source = CornerSymmetriesSource::create();
+    let moves_source = MovesSource::create(&mut IgnoreProgress).unwrap();
+    let corner_source = CornerSymmetriesSource::create(&mut IgnoreProgress).unwrap();
 
-    let table = DataSource::create_phase_2_pruning(&moves_source, &corner_source);
+    let table = DataSource::create_phase_2_pruning(&moves_source, &corner_source, &mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(table);
 }
 
 #[test]
 fn test_fs_sym() {
-    let flip_slice_source = FlipSliceSource::create();
+    let flip_slice_source = FlipSliceSource::create(&mut IgnoreProgress).unwrap();
     let table = DataSource::make_flip_slice_sym(&flip_slice_source);
     insta::assert_debug_snapshot!(table);
 }
 
 #[test]
 fn test_phase_one_pruning() {
-    let moves_source = MovesSource::create();
-    let flip_slice_source = FlipSliceSource::create();
+    let moves_source = MovesSource::create(&mut IgnoreProgress).unwrap();
+    let flip_slice_source = FlipSliceSource::create(&mut IgnoreProgress).unwrap();
 
-    let table = DataSource::create_phase_1_pruning(false, &moves_source, &flip_slice_source);
+    let table = DataSource::create_phase_1_pruning(false, &moves_source, &flip_slice_source, &mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(table);
 }
 
 #[test]
 fn test_phase_one_pruning_quick() {
-    let moves_source = MovesSource::create();
-    let flip_slice_source = FlipSliceSource::create();
+    let moves_source = MovesSource::create(&mut IgnoreProgress).unwrap();
+    let flip_slice_source = FlipSliceSource::create(&mut IgnoreProgress).unwrap();
 
-    let table = DataSource::create_phase_1_pruning(true, &moves_source, &flip_slice_source);
+    let table = DataSource::create_phase_1_pruning(true, &moves_source, &flip_slice_source, &mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(table);
 }
 
 #[test]
 fn test_phase_two_ud_edge_merge() {
-    let table = DataSource::create_phase_2_edge_merge();
+    let table = DataSource::create_phase_2_edge_merge(&mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(table);
 }
 
@@ -260,19 +261,19 @@ fn test_up_down_edges_conjugation() {
 
 #[test]
 fn test_create_corner_symmetries() {
-    let css = CornerSymmetriesSource::create();
+    let css = CornerSymmetriesSource::create(&mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(css.corner_class_index);
 }
 
 #[test]
 fn test_create_flip_slice_symmetries() {
-    let fss = FlipSliceSource::create();
+    let fss = FlipSliceSource::create(&mut IgnoreProgress).unwrap();
     insta::assert_debug_snapshot!(fss.flip_slice_class_index);
 }
 
 #[test]
 fn test_create_flip_slice_rep() {
-    let fss = FlipSliceSource::create();    
+    let fss = FlipSliceSource::create(&mut IgnoreProgress).unwrap();    
     insta::assert_debug_snapshot!(fss.flip_slice_rep);
 }
 
@@ -351,6 +352,50 @@ fn test_solve_cubes() {
 }
 
 
+#[test]
+fn test_solve_cubes_in_each_metric() {
+    let data_source = Rc::new(DataSource::create(true));
+
+    assert_eq!(Metric::QuarterTurn.count(&[Move::R2]), 2);
+    assert_eq!(Metric::SliceTurn.count(&[Move::R1, Move::L3]), 1);
+
+    for seed in 1..20 {
+        let cube = CubieCube::random_cube(seed);
+        let solve = |settings: SolveSettings| {
+            let metric = settings.metric;
+            let solution =
+                Solver::get_solution(cube.clone(), data_source.clone(), settings).unwrap();
+
+            let mut solved_cube = cube.clone();
+            for &m in solution.iter() {
+                solved_cube = m.apply(&solved_cube);
+            }
+            assert_eq!(solved_cube, CubieCube::default(), "Metric {}", metric);
+            solution
+        };
+
+        let half_turn = solve(SolveSettings::default());
+        solve(SolveSettings {
+            metric: Metric::SliceTurn,
+            ..Default::default()
+        });
+
+        //Searching in quarter turns until it is at least as short in quarter turns as the half turn solution
+        let target = Metric::QuarterTurn.count(&half_turn);
+        let quarter_turn = solve(SolveSettings {
+            metric: Metric::QuarterTurn,
+            stopping_length: (target + 1) as u8,
+            max_iterations: 100000,
+            ..Default::default()
+        });
+        assert!(
+            Metric::QuarterTurn.count(&quarter_turn) <= target,
+            "Seed {}",
+            seed
+        );
+    }
+}
+
 fn test_solver(cube: &CubieCube, data_source: Rc<DataSource>) {
     let solution = Solver::get_solution(cube.clone(), data_source, SolveSettings::default());
 
@@ -363,6 +408,612 @@ fn test_solver(cube: &CubieCube, data_source: Rc<DataSource>) {
     assert_eq!(solved_cube, CubieCube::default());
 }
 
+#[test]
+fn test_solve_stop_reasons() {
+    let data_source = Rc::new(DataSource::create(true));
+    let cube = CubieCube::random_cube(7);
+
+    let settings = SolveSettings {
+        stopping_length: 30,
+        ..Default::default()
+    };
+    let outcome = Solver::solve(cube.clone(), data_source.clone(), settings);
+    assert_eq!(outcome.stop_reason, StopReason::FoundTarget);
+    assert_eq!(Algorithm::from(outcome.solution.unwrap()).apply(&cube), CubieCube::default());
+
+    let settings = SolveSettings {
+        stopping_length: 0,
+        max_iterations: 100,
+        ..Default::default()
+    };
+    let outcome = Solver::solve(cube.clone(), data_source.clone(), settings);
+    assert_eq!(outcome.stop_reason, StopReason::IterationLimit);
+
+    let start = std::time::Instant::now();
+    let settings = SolveSettings {
+        stopping_length: 0,
+        max_iterations: usize::MAX,
+        time_budget: Some(std::time::Duration::from_millis(50)),
+        ..Default::default()
+    };
+    let outcome = Solver::solve(cube.clone(), data_source.clone(), settings);
+    assert_eq!(outcome.stop_reason, StopReason::TimedOut);
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    assert_eq!(Algorithm::from(outcome.solution.unwrap()).apply(&cube), CubieCube::default());
+
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    let settings = SolveSettings {
+        cancellation: Some(cancellation),
+        ..Default::default()
+    };
+    let outcome = Solver::solve(cube, data_source, settings);
+    assert_eq!(outcome.stop_reason, StopReason::Cancelled);
+    assert_eq!(outcome.solution, None);
+    assert_eq!(outcome.report.nodes_expanded, 0);
+}
+
+#[test]
+fn test_solve_report() {
+    let data_source = Rc::new(DataSource::create(true));
+    let cube = CubieCube::random_cube(3);
+    let settings = SolveSettings {
+        stopping_length: 0,
+        max_iterations: 2000,
+        ..Default::default()
+    };
+
+    let outcome = Solver::solve(cube.clone(), data_source, settings);
+    let report = outcome.report;
+
+    assert_eq!(report.nodes_expanded, 2000);
+    assert!(report.phase_1.nodes > 0);
+    assert!(report.phase_2.nodes > 0);
+    assert!(report.phase_1.nodes + report.phase_2.nodes <= report.nodes_expanded);
+    assert!(report.max_queue_size > 0);
+    assert!(report.seen_size > 0);
+
+    assert!(!report.improvements.is_empty());
+    for (before, after) in report.improvements.iter().tuple_windows() {
+        assert!(after.cost < before.cost);
+        assert!(after.iteration > before.iteration);
+    }
+
+    let solution = outcome.solution.unwrap();
+    assert!(solution.len() <= report.improvements.last().unwrap().cost as usize);
+    assert!(report.winning_start.is_some());
+
+    let merged = SolveReport::merge([report.clone(), report.clone()]);
+    assert_eq!(merged.nodes_expanded, 2 * report.nodes_expanded);
+    assert_eq!(merged.max_queue_size, report.max_queue_size);
+    assert_eq!(merged.improvements, report.improvements);
+}
+
+#[test]
+fn test_solve_report_with_several_solutions() {
+    let data_source = Rc::new(DataSource::create(true));
+    let cube = CubieCube::random_cube(4);
+    let settings = SolveSettings {
+        max_iterations: 100000,
+        ..Default::default()
+    };
+
+    let starts = Solver::create_start_states(&cube, &data_source);
+    let mut coordinator =
+        SerialSolveCoordinator::new(data_source, starts, &settings, SolutionLimit::Shortest(5));
+    coordinator.solve(&settings);
+
+    assert!(coordinator.solutions.len() > 1);
+    assert!(!coordinator.report.improvements.is_empty());
+    for (before, after) in coordinator.report.improvements.iter().tuple_windows() {
+        assert!(after.cost < before.cost);
+    }
+}
+
+#[test]
+fn test_parallel_solver() {
+    let data_source = Arc::new(DataSource::create(true));
+
+    //With the same total budget, searching the whole of it
+    let settings = SolveSettings {
+        stopping_length: 0,
+        max_iterations: 10000,
+        ..Default::default()
+    };
+    let mut serial_total = 0;
+    let mut parallel_total = 0;
+    for seed in 1..20 {
+        let cube = CubieCube::random_cube(seed);
+        let serial = Solver::solve(cube.clone(), data_source.clone(), settings.clone());
+        let parallel = ParallelSolver::solve(cube.clone(), data_source.clone(), settings.clone());
+
+        let parallel_solution = parallel.solution.unwrap();
+        assert_eq!(
+            Algorithm::from(parallel_solution.clone()).apply(&cube),
+            CubieCube::default()
+        );
+        assert!(parallel.report.winning_start.is_some());
+        serial_total += serial.solution.unwrap().len();
+        parallel_total += parallel_solution.len();
+    }
+    assert!(
+        parallel_total <= serial_total,
+        "{} > {}",
+        parallel_total,
+        serial_total
+    );
+
+    //Given a larger budget, searching until it is at least as short as the serial solution
+    for seed in 1..20 {
+        let cube = CubieCube::random_cube(seed);
+        let serial = Solver::solve(cube.clone(), data_source.clone(), SolveSettings::default());
+        let serial_length = serial.solution.unwrap().len();
+
+        let settings = SolveSettings {
+            stopping_length: (serial_length + 1) as u8,
+            max_iterations: 100000,
+            ..Default::default()
+        };
+        let parallel = ParallelSolver::solve(cube.clone(), data_source.clone(), settings);
+        let parallel_length = parallel.solution.unwrap().len();
+        assert!(
+            parallel_length <= serial_length,
+            "Seed {}: {} > {}",
+            seed,
+            parallel_length,
+            serial_length
+        );
+    }
+
+    let cubes = (1..20).map(CubieCube::random_cube).collect_vec();
+    let outcomes = ParallelSolver::solve_batch(&cubes, data_source, SolveSettings::default());
+    assert_eq!(outcomes.len(), cubes.len());
+    for (cube, outcome) in cubes.iter().zip(outcomes) {
+        assert_eq!(
+            Algorithm::from(outcome.solution.unwrap()).apply(cube),
+            CubieCube::default()
+        );
+    }
+}
+
+#[test]
+fn test_get_solution_between() {
+    let data_source = Rc::new(DataSource::create(true));
+
+    for seed in 1..10 {
+        let start = CubieCube::random_cube(seed);
+        let target = CubieCube::random_cube(seed + 100);
+
+        let solution = Solver::get_solution_between(
+            start.clone(),
+            target.clone(),
+            data_source.clone(),
+            SolveSettings::default(),
+        )
+        .unwrap();
+
+        assert_eq!(Algorithm::from(solution).apply(&start), target);
+    }
+
+    let checkerboard =
+        Algorithm::from(Move::parse_sequence("U2 D2 F2 B2 L2 R2").unwrap()).apply(&CubieCube::default());
+    let solution = Solver::get_solution_between(
+        CubieCube::default(),
+        checkerboard.clone(),
+        data_source,
+        SolveSettings::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        Algorithm::from(solution).apply(&CubieCube::default()),
+        checkerboard
+    );
+}
+
+#[test]
+fn test_get_solutions() {
+    let data_source = Rc::new(DataSource::create(true));
+
+    for seed in 1..5 {
+        let cube = CubieCube::random_cube(seed);
+        let settings = SolveSettings {
+            max_iterations: 100000,
+            ..Default::default()
+        };
+        let solutions = Solver::get_solutions(
+            cube.clone(),
+            data_source.clone(),
+            settings,
+            SolutionLimit::Shortest(5),
+        );
+
+        assert!(solutions.len() > 1);
+        assert!(solutions.len() <= 5);
+        assert!(solutions.windows(2).all(|w| w[0].len() <= w[1].len()));
+        assert!(solutions.iter().all_unique());
+        //Solutions which only differ in the order of commuting moves are the same
+        assert!(solutions
+            .iter()
+            .map(|s| Algorithm::from(s.clone()).simplify())
+            .all_unique());
+
+        for solution in solutions {
+            assert_eq!(
+                Algorithm::from(solution).apply(&cube),
+                CubieCube::default()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_short_scramble_optimal_solver() {
+    let data_source = Rc::new(DataSource::create(true));
+    let solver = ShortScrambleOptimalSolver::new(data_source);
+
+    for m in Move::ALLMOVES {
+        let solution = solver.solve(m.get_cube(), 20).unwrap();
+        assert_eq!(solution.length, 1);
+        assert_eq!(solution.moves, vec![m.inverse()]);
+    }
+
+    for seed in 1..10 {
+        let mut rng: StdRng = rand::SeedableRng::seed_from_u64(seed);
+        let scramble = (0..10)
+            .map(|_| Move::ALLMOVES[rng.gen_range(0..18)])
+            .collect_vec();
+        let cube = Algorithm::from(scramble.clone()).apply(&CubieCube::default());
+
+        let solution = solver.solve(&cube, 20).unwrap();
+
+        assert!(solution.length as usize <= Algorithm::from(scramble).simplify().len());
+        assert_eq!(solution.moves.len(), solution.length as usize);
+        assert_eq!(
+            Algorithm::from(solution.moves).apply(&cube),
+            CubieCube::default()
+        );
+    }
+}
+
+#[test]
+fn test_restricted_solver() {
+    let data_source = Rc::new(DataSource::create(true));
+    let solver = RestrictedSolver::for_faces(data_source, &[FaceColor::Right, FaceColor::Up]);
+
+    for seed in 1..5 {
+        let mut rng: StdRng = rand::SeedableRng::seed_from_u64(seed);
+        let scramble = (0..8)
+            .map(|_| {
+                [Move::R1, Move::R2, Move::R3, Move::U1, Move::U2, Move::U3][rng.gen_range(0..6)]
+            })
+            .collect_vec();
+        let cube = Algorithm::from(scramble).apply(&CubieCube::default());
+
+        let solution = solver.solve(&cube, 20).unwrap();
+
+        assert!(solution.len() <= 8);
+        assert!(solution
+            .iter()
+            .all(|m| m.get_color() == FaceColor::Right || m.get_color() == FaceColor::Up));
+        assert_eq!(Algorithm::from(solution).apply(&cube), CubieCube::default());
+    }
+
+    assert_eq!(
+        solver.solve(Move::F1.get_cube(), 20),
+        Err(RestrictedSolveError::NotReachable)
+    );
+
+    //Each coordinate of this cube can be reached with <U,D>, but not all of them at once
+    let data_source = Rc::new(DataSource::create(true));
+    let solver = RestrictedSolver::for_faces(data_source, &[FaceColor::Up, FaceColor::Down]);
+    let corners = Algorithm::from(vec![Move::U1, Move::D1]).apply(&CubieCube::default());
+    let cube = CubieCube {
+        corner_positions: corners.corner_positions,
+        ..Move::U2.get_cube().clone()
+    };
+    assert!(cube.verify().is_ok());
+    assert_eq!(
+        solver.solve(&cube, 20),
+        Err(RestrictedSolveError::NotReachable)
+    );
+
+    let data_source = Rc::new(DataSource::create(true));
+    let face_turns = ExtendedMove::parse_sequence("U D").unwrap();
+    let solver = RestrictedSolver::from_generators(data_source.clone(), &face_turns).unwrap();
+    assert_eq!(solver.solve(Move::U2.get_cube(), 20), Ok(vec![Move::U2]));
+
+    let slice_turns = ExtendedMove::parse_sequence("M U").unwrap();
+    assert_eq!(
+        RestrictedSolver::from_generators(data_source, &slice_turns).err(),
+        Some(RestrictedSolveError::UnsupportedMove(slice_turns[0]))
+    );
+}
+
+#[test]
+fn test_facelet_strings() {
+    let solved = "UUUUUUUUURRRRRRRRRFFFFFFFFFDDDDDDDDDLLLLLLLLLBBBBBBBBB";
+    assert_eq!(FaceletCube::default().to_string(), solved);
+    assert_eq!(solved.parse::<FaceletCube>(), Ok(FaceletCube::default()));
+
+    for seed in 0..10 {
+        let cube = CubieCube::random_cube(seed);
+        let facelets: FaceletCube = cube.clone().into();
+        let text = facelets.to_string();
+
+        assert_eq!(
+            CubieCube::try_from(text.parse::<FaceletCube>().unwrap()),
+            Ok(cube.clone())
+        );
+
+        let colors = ColorScheme::COLORS.format(&facelets);
+        assert_eq!(ColorScheme::COLORS.parse(&colors), Ok(facelets));
+    }
+
+    let r = Move::R1.get_cube().clone().into();
+    assert_eq!(
+        ColorScheme::COLORS.format(&r),
+        "YYRYYRYYRGGGGGGGGGRRWRRWRRWWWOWWOWWOBBBBBBBBBYOOYOOYOO"
+    );
+
+    let scheme = ColorScheme::new(['w', 'r', 'g', 'y', 'o', 'b']).unwrap();
+    assert_eq!(
+        scheme.parse("wwwwwwwwwrrrrrrrrrgggggggggyyyyyyyyyooooooooobbbbbbbbb"),
+        Ok(FaceletCube::default())
+    );
+    assert_eq!(ColorScheme::new(['W', 'W', 'G', 'Y', 'O', 'B']), None);
+
+    assert_eq!(
+        "UUU".parse::<FaceletCube>(),
+        Err(FaceletParseError::WrongLength(3))
+    );
+    assert_eq!(
+        solved.replace('B', "X").parse::<FaceletCube>(),
+        Err(FaceletParseError::UnknownLetter { letter: 'X', index: 45 })
+    );
+    assert_eq!(
+        FaceletCube::CLEARED.to_string().parse::<FaceletCube>(),
+        Ok(FaceletCube::CLEARED)
+    );
+}
+
+#[test]
+fn test_cube_errors() {
+    let solved = "UUUUUUUUURRRRRRRRRFFFFFFFFFDDDDDDDDDLLLLLLLLLBBBBBBBBB";
+    let try_parse = |text: &str| CubieCube::try_from(text.parse::<FaceletCube>().unwrap());
+
+    let mut flipped = solved.to_string();
+    flipped.replace_range(7..8, "F");
+    flipped.replace_range(19..20, "U");
+    let error = try_parse(&flipped).unwrap_err();
+    assert_eq!(error, CubeError::EdgeFlip { position: EdgePosition::Uf });
+    assert_eq!(error.get_facelets(), vec![FaceletPosition::U8, FaceletPosition::F2]);
+    assert_eq!(error.get_suggested_fix(), "Flip the Uf edge");
+
+    //Repainting a sticker gives a new error, so the highlighted facelets change
+    let mut painted = FaceletCube::CLEARED;
+    let before = painted.validate_colors().unwrap_err();
+    painted.facelets[FaceletPosition::U1 as usize] = Some(FaceColor::Up);
+    let after = painted.validate_colors().unwrap_err();
+    assert!(before.get_facelets().contains(&FaceletPosition::U1));
+    assert!(!after.get_facelets().contains(&FaceletPosition::U1));
+    assert_ne!(before.get_facelets(), after.get_facelets());
+
+    let mut twisted = CubieCube::default();
+    twisted.corner_orientations[0] = CornerOrientation::One;
+    assert_eq!(
+        twisted.verify(),
+        Err(CubeError::CornerTwist {
+            position: CornerPosition::Urf,
+            clockwise: false
+        })
+    );
+
+    let mut swapped = CubieCube::default();
+    swapped.edge_positions.swap(0, 1);
+    assert_eq!(
+        swapped.verify(),
+        Err(CubeError::Parity {
+            swap: [EdgePosition::Ur, EdgePosition::Uf]
+        })
+    );
+
+    let mut duplicated = CubieCube::default();
+    duplicated.edge_positions[11] = EdgePosition::Ur;
+    assert_eq!(
+        duplicated.verify(),
+        Err(CubeError::DuplicateEdge {
+            edge: EdgePosition::Ur,
+            positions: vec![EdgePosition::Ur, EdgePosition::Br]
+        })
+    );
+
+    let mut miscolored = solved.to_string();
+    miscolored.replace_range(8..9, "D");
+    miscolored.replace_range(27..28, "U");
+    assert_eq!(
+        try_parse(&miscolored),
+        Err(CubeError::UnknownCorner {
+            position: CornerPosition::Urf,
+            colors: [FaceColor::Down, FaceColor::Right, FaceColor::Front]
+        })
+    );
+
+    let mut too_many = solved.to_string();
+    too_many.replace_range(0..1, "R");
+    assert_eq!(
+        try_parse(&too_many),
+        Err(CubeError::WrongColorCount {
+            color: FaceColor::Up,
+            count: 8
+        })
+    );
+
+    assert_eq!(
+        CubieCube::try_from(FaceletCube::CLEARED).unwrap_err().get_facelets().len(),
+        48
+    );
+}
+
+#[test]
+fn test_data_source_round_trip() {
+    let data_source = DataSource::create(true);
+    let mut bytes: Vec<u8> = Vec::new();
+    data_source.write_to(&mut bytes).unwrap();
+
+    let read = DataSource::read_from(bytes.as_slice()).unwrap();
+
+    assert!(read.quick);
+    assert_eq!(read.phase_1_pruning, data_source.phase_1_pruning);
+    assert_eq!(read.phase_2_pruning, data_source.phase_2_pruning);
+    assert_eq!(read.corners_source.corner_rep, data_source.corners_source.corner_rep);
+    assert_eq!(read.moves_source.twist_conjugation, data_source.moves_source.twist_conjugation);
+
+    test_solver(&CubieCube::random_cube(1), Rc::new(read));
+
+    let mut corrupted = bytes.clone();
+    let middle = corrupted.len() / 2;
+    corrupted[middle] ^= 1;
+    assert!(matches!(
+        DataSource::read_from(corrupted.as_slice()),
+        Err(DataSourceReadError::ChecksumMismatch)
+    ));
+
+    let mut wrong_version = bytes.clone();
+    wrong_version[4] = 99;
+    assert!(matches!(
+        DataSource::read_from(wrong_version.as_slice()),
+        Err(DataSourceReadError::UnsupportedVersion(99))
+    ));
+
+    let mut wrong_size = bytes.clone();
+    wrong_size[9] ^= 1;
+    assert!(matches!(
+        DataSource::read_from(wrong_size.as_slice()),
+        Err(DataSourceReadError::SizeMismatch { name: "NFLIPSLICECLASS", .. })
+    ));
+
+    assert!(matches!(
+        DataSource::read_from(&bytes[..bytes.len() - 100]),
+        Err(DataSourceReadError::Io(_))
+    ));
+}
+
+#[test]
+fn test_masked_solver() {
+    let cross_solver = MaskedSolver::new(PieceMask::cross());
+
+    for seed in 1..5 {
+        let cube = CubieCube::random_cube(seed);
+        let solution = cross_solver.solve(&cube, 8).unwrap();
+
+        assert!(solution.len() <= 8);
+        assert!(PieceMask::cross().matches(&Algorithm::from(solution).apply(&cube)));
+    }
+
+    let pair_mask = PieceMask::cross_and_pair(CornerPosition::Dfr, EdgePosition::Fr);
+    let pair_solver = MaskedSolver::new(pair_mask.clone());
+    let cube = "D R U R' U' F2 L".parse::<Algorithm>().unwrap().apply(&CubieCube::default());
+    let solution = pair_solver.solve(&cube, 20).unwrap();
+
+    assert!(solution.len() <= 7);
+    assert!(pair_mask.matches(&Algorithm::from(solution).apply(&cube)));
+
+    let oll_solver = MaskedSolver::new(PieceMask::oriented_last_layer());
+    let cube = "U F R U R' U' F' U2".parse::<Algorithm>().unwrap().apply(&CubieCube::default());
+    let solution = oll_solver.solve(&cube, 20).unwrap();
+
+    assert!(solution.len() <= 6);
+    assert!(PieceMask::oriented_last_layer().matches(&Algorithm::from(solution).apply(&cube)));
+
+    assert_eq!(oll_solver.solve(&CubieCube::default(), 20), Some(vec![]));
+}
+
+#[test]
+fn test_scrambler() {
+    let data_source = Rc::new(DataSource::create(true));
+    let scrambler = Scrambler::new(data_source.clone());
+
+    for seed in 1..40 {
+        let scramble = scrambler.scramble(seed).unwrap();
+
+        assert!(
+            (19..=21).contains(&scramble.moves.len()),
+            "{} has {} moves",
+            scramble,
+            scramble.moves.len()
+        );
+        assert_eq!(
+            Algorithm::from(scramble.moves.clone()).apply(&CubieCube::default()),
+            scramble.get_cube()
+        );
+        assert_eq!(scrambler.scramble(scramble.seed), Some(scramble));
+    }
+
+    //No state needs 30 moves, so every seed is skipped
+    let mut impossible = Scrambler::new(data_source);
+    impossible.min_length = 30;
+    impossible.max_length = 30;
+    impossible.max_attempts = 3;
+    assert_eq!(impossible.scramble(1), None);
+}
+
+#[test]
+fn test_generation_progress() {
+    let mut reports: Vec<GenerationProgress> = Vec::new();
+    let data_source =
+        DataSource::create_with_observer(true, &mut |progress: GenerationProgress| {
+            reports.push(progress);
+            GenerationControl::Continue
+        });
+    assert!(data_source.is_ok());
+
+    for table in GenerationTable::ALL {
+        assert!(
+            reports.iter().any(|p| p.table == table),
+            "{} was not reported",
+            table
+        );
+    }
+    for (before, after) in reports.iter().tuple_windows() {
+        assert!(after.overall_fraction() >= before.overall_fraction());
+        if before.table == after.table {
+            assert!(after.done >= before.done);
+            assert!(after.depth >= before.depth);
+        }
+    }
+    for table in GenerationTable::ALL {
+        let last = reports.iter().rev().find(|p| p.table == table).unwrap();
+        assert_eq!(last.fraction(), 1.0, "{} did not finish", table);
+    }
+    assert_eq!(reports.last().unwrap().overall_fraction(), 1.0);
+
+    for table in GenerationTable::ALL {
+        let cancelled =
+            DataSource::create_with_observer(true, &mut |progress: GenerationProgress| {
+                if progress.table == table {
+                    GenerationControl::Cancel
+                } else {
+                    GenerationControl::Continue
+                }
+            });
+        assert_eq!(cancelled.err(), Some(GenerationCancelled { table }));
+    }
+
+    let cancelled = DataSource::create_with_observer(true, &mut |progress: GenerationProgress| {
+        if progress.table == GenerationTable::Phase2Pruning && progress.depth >= 3 {
+            GenerationControl::Cancel
+        } else {
+            GenerationControl::Continue
+        }
+    });
+    assert_eq!(
+        cancelled.err(),
+        Some(GenerationCancelled {
+            table: GenerationTable::Phase2Pruning
+        })
+    );
+}
+
 #[test]
 fn test_symmetry_cubes() {
     assert_eq!(SYMMETRY_CUBES[0], CubieCube::default());
@@ -381,3 +1032,198 @@ fn test_symmetry_cubes() {
         URF3_SYMMETRY.multiply(&MIRROR_LR2_SYMMETRY)
     );
 }
+
+#[test_case(1)]
+#[test_case(2)]
+#[test_case(3)]
+#[test_case(4)]
+#[test_case(5)]
+fn test_cubie_cube_share_code(seed: u64) {
+    let cube = CubieCube::random_cube(seed);
+    let code = cube.to_share_code();
+
+    assert_eq!(code.len(), 18);
+    assert!(code
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+    assert_eq!(CubieCube::from_share_code(&code), Ok(cube));
+}
+
+#[test]
+fn test_share_code_errors() {
+    let solved = CubieCube::default().to_share_code();
+    assert_eq!(
+        CubieCube::from_share_code(&solved),
+        Ok(CubieCube::default())
+    );
+
+    assert_eq!(
+        CubieCube::from_share_code("AAA"),
+        Err(ShareCodeError::WrongLength {
+            expected: 18,
+            found: 3
+        })
+    );
+    assert_eq!(
+        CubieCube::from_share_code(&solved.replacen('A', "!", 1)),
+        Err(ShareCodeError::UnknownCharacter {
+            character: '!',
+            index: 0
+        })
+    );
+    assert!(matches!(
+        CubieCube::from_share_code(&("___".to_string() + &solved[3..])),
+        Err(ShareCodeError::CoordinateOutOfRange {
+            coordinate: "flip",
+            ..
+        })
+    ));
+
+    //Swapping two edges can't be done by turning the faces
+    let mut swapped = CubieCube::default();
+    swapped.edge_positions.swap(0, 1);
+    assert!(matches!(
+        CubieCube::from_share_code(&swapped.to_share_code()),
+        Err(ShareCodeError::InvalidCube(CubeError::Parity { .. }))
+    ));
+
+    //The slice edges are moved into the down layer, but the down edges are left in place
+    let mut slice_in_down_layer = CubieCube::default();
+    slice_in_down_layer.edge_positions.rotate_left(4);
+    let overlapping = CoordinateCube {
+        slice_sorted: slice_in_down_layer.get_slice_sorted(),
+        ..CoordinateCube::from(CubieCube::default())
+    };
+    assert_eq!(
+        CubieCube::try_from(overlapping),
+        Err(ShareCodeError::OverlappingEdges)
+    );
+}
+
+#[test]
+fn test_facelet_cube_share_code() {
+    let mut cube: FaceletCube = CubieCube::random_cube(7).into();
+    cube.facelets[0] = None;
+    cube.facelets[53] = None;
+
+    let code = cube.to_share_code();
+    assert_eq!(code.len(), 27);
+    assert_eq!(FaceletCube::from_share_code(&code), Ok(cube));
+    assert_eq!(
+        FaceletCube::from_share_code(&FaceletCube::CLEARED.to_share_code()),
+        Ok(FaceletCube::CLEARED)
+    );
+    assert_eq!(
+        FaceletCube::from_share_code(&"_".repeat(27)),
+        Err(ShareCodeError::UnknownCharacter {
+            character: '_',
+            index: 0
+        })
+    );
+}
+
+#[test]
+fn test_moves_share_code() {
+    let moves = Move::parse_sequence("R U2 F' D L2 B'").unwrap();
+    let code = Move::to_share_code(&moves);
+
+    assert_eq!(code, "R1U2F3D1L2B3");
+    assert_eq!(Move::from_share_code(&code), Ok(moves));
+    assert_eq!(Move::from_share_code(""), Ok(vec![]));
+    assert!(matches!(
+        Move::from_share_code("R1U"),
+        Err(ShareCodeError::InvalidMove(MoveParseError::BadSuffix {
+            column: 3,
+            ..
+        }))
+    ));
+    assert!(matches!(
+        Move::from_share_code("R1X2"),
+        Err(ShareCodeError::InvalidMove(MoveParseError::UnknownFace {
+            column: 3,
+            ..
+        }))
+    ));
+}
+
+#[test_case("r", false, "R")]
+#[test_case("R", false, "R")]
+#[test_case("r", true, "R'")]
+#[test_case("R", true, "R'")]
+#[test_case("u", false, "U")]
+#[test_case("b", true, "B'")]
+fn test_letters_key_map(key: &str, shift: bool, expected: &str) {
+    assert_eq!(
+        KeyMap::Letters.get_move(key, shift),
+        Some(expected.parse().unwrap())
+    );
+    assert_eq!(KeyMap::Off.get_move(key, shift), None);
+}
+
+#[test_case("j", "U")]
+#[test_case("f", "U'")]
+#[test_case("i", "R")]
+#[test_case("k", "R'")]
+#[test_case("h", "F")]
+#[test_case("g", "F'")]
+#[test_case("s", "D")]
+#[test_case("l", "D'")]
+#[test_case("d", "L")]
+#[test_case("e", "L'")]
+#[test_case("w", "B")]
+#[test_case("o", "B'")]
+fn test_speedcube_key_map(key: &str, expected: &str) {
+    let m: Move = expected.parse().unwrap();
+
+    assert_eq!(KeyMap::Speedcube.get_move(key, false), Some(m));
+    assert_eq!(
+        KeyMap::Speedcube.get_move(&key.to_uppercase(), true),
+        Some(m)
+    );
+    assert_eq!(
+        KeyMap::Speedcube.get_key_description(m),
+        Some(key.to_uppercase())
+    );
+}
+
+#[test]
+fn test_key_map_round_trip() {
+    for key_map in KeyMap::ALL {
+        for key in ["Enter", "Shift", "ArrowUp", "F1", "Rr", ""] {
+            assert_eq!(
+                key_map.get_move(key, false),
+                None,
+                "{} in {:?}",
+                key,
+                key_map
+            );
+        }
+        assert_eq!(key_map.get_move("x", false), None);
+
+        for m in Move::ALLMOVES {
+            let Some(description) = key_map.get_key_description(m) else {
+                continue;
+            };
+            let (key, shift) = match description.strip_prefix("Shift+") {
+                Some(key) => (key, true),
+                None => (description.as_str(), false),
+            };
+            assert_eq!(
+                key_map.get_move(key, shift),
+                Some(m),
+                "{} in {:?}",
+                description,
+                key_map
+            );
+        }
+    }
+
+    assert!(Move::ALLMOVES
+        .into_iter()
+        .all(|m| KeyMap::Off.get_key_description(m).is_none()));
+    assert_eq!(KeyMap::Letters.get_key_description(Move::R2), None);
+    assert_eq!(
+        KeyMap::Letters.get_key_description(Move::F3),
+        Some("Shift+F".to_string())
+    );
+}